@@ -0,0 +1,40 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::BinaryHeap;
+use test::{Bencher, black_box};
+
+const SIZE: usize = 10_000;
+
+fn data() -> Vec<usize> {
+    // a fixed permutation of 0..SIZE
+    (0..SIZE).map(|i| (i * 7919) % SIZE).collect()
+}
+
+#[bench]
+fn bench_from_vec(b: &mut Bencher) {
+    let data = data();
+    b.iter(|| {
+        let heap = BinaryHeap::from(data.clone());
+        black_box(heap);
+    })
+}
+
+#[bench]
+fn bench_push(b: &mut Bencher) {
+    let data = data();
+    b.iter(|| {
+        let mut heap = BinaryHeap::with_capacity(SIZE);
+        for &x in &data {
+            heap.push(x);
+        }
+        black_box(heap);
+    })
+}
@@ -19,6 +19,7 @@
 extern crate rand;
 extern crate test;
 
+mod binary_heap;
 mod btree;
 mod linked_list;
 mod string;
@@ -60,6 +60,26 @@ fn bench_push_char_two_bytes(b: &mut Bencher) {
     });
 }
 
+#[bench]
+fn bench_extend_char_one_byte(b: &mut Bencher) {
+    b.bytes = REPETITIONS;
+    b.iter(|| {
+        let mut r = String::new();
+        r.extend(repeat('a').take(REPETITIONS as usize));
+        r
+    });
+}
+
+#[bench]
+fn bench_extend_char_two_bytes(b: &mut Bencher) {
+    b.bytes = REPETITIONS * 2;
+    b.iter(|| {
+        let mut r = String::new();
+        r.extend(repeat('â').take(REPETITIONS as usize));
+        r
+    });
+}
+
 #[bench]
 fn from_utf8_lossy_100_ascii(b: &mut Bencher) {
     let s = b"Hello there, the quick brown fox jumped over the lazy dog! \
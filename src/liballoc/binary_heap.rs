@@ -514,8 +514,8 @@ impl<T: Ord> BinaryHeap<T> {
     /// The capacity will remain at least as large as both the length
     /// and the supplied value.
     ///
-    /// Panics if the current capacity is smaller than the supplied
-    /// minimum capacity.
+    /// If the current capacity is less than or equal to the lower limit, this
+    /// is a no-op.
     ///
     /// # Examples
     ///
@@ -583,6 +583,34 @@ impl<T: Ord> BinaryHeap<T> {
         self.sift_up(0, old_len);
     }
 
+    /// Returns a mutable slice of the underlying vector, in arbitrary order.
+    ///
+    /// Modifying the elements may break the heap property; call [`rebuild`]
+    /// once all modifications are done to restore it.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(binary_heap_rebuild)]
+    /// use std::collections::BinaryHeap;
+    /// let mut heap = BinaryHeap::from(vec![1, 2, 3]);
+    ///
+    /// heap.as_mut_slice()[0] = 0;
+    /// heap.rebuild();
+    ///
+    /// assert_eq!(heap.peek(), Some(&2));
+    /// ```
+    ///
+    /// [`rebuild`]: #method.rebuild
+    #[unstable(feature = "binary_heap_rebuild",
+               reason = "exposing the backing slice breaks the heap invariant until `rebuild`",
+               issue = "0")]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
     /// Consumes the `BinaryHeap` and returns the underlying vector
     /// in arbitrary order.
     ///
@@ -633,6 +661,33 @@ impl<T: Ord> BinaryHeap<T> {
         self.into_vec()
     }
 
+    /// Consumes the `BinaryHeap` and returns an iterator which yields elements
+    /// in heap order (largest first).
+    ///
+    /// Unlike [`into_sorted_vec`], elements are popped lazily, so stopping
+    /// early only pays for the elements actually taken.
+    ///
+    /// [`into_sorted_vec`]: #method.into_sorted_vec
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(binary_heap_into_iter_sorted)]
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let heap = BinaryHeap::from(vec![1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(heap.into_iter_sorted().take(2).collect::<Vec<_>>(), [5, 4]);
+    /// ```
+    #[unstable(feature = "binary_heap_into_iter_sorted",
+               reason = "may be merged with `into_sorted_vec` or `drain_sorted`",
+               issue = "0")]
+    pub fn into_iter_sorted(self) -> IntoIterSorted<T> {
+        IntoIterSorted { inner: self }
+    }
+
     // The implementations of sift_up and sift_down use unsafe blocks in
     // order to move an element out of the vector (leaving behind a
     // hole), shift along the others and move the removed element back into the
@@ -749,6 +804,71 @@ impl<T: Ord> BinaryHeap<T> {
         self.len() == 0
     }
 
+    /// Returns `true` if the binary heap contains an element equal to the
+    /// given value.
+    ///
+    /// This performs a linear scan of the underlying storage and takes
+    /// O(n) time.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(binary_heap_contains)]
+    ///
+    /// use std::collections::BinaryHeap;
+    /// let heap = BinaryHeap::from(vec![1, 5, 2]);
+    ///
+    /// assert!(heap.contains(&5));
+    /// assert!(!heap.contains(&3));
+    /// ```
+    #[unstable(feature = "binary_heap_contains",
+               reason = "linear-time search on a heap may be surprising",
+               issue = "0")]
+    pub fn contains(&self, x: &T) -> bool
+        where T: PartialEq
+    {
+        self.data.iter().any(|e| e == x)
+    }
+
+    /// Removes and returns the first element, in storage order, for which
+    /// `pred` returns `true`, or `None` if there is no such element.
+    ///
+    /// This performs a linear scan of the underlying storage, so it takes
+    /// O(n) time; restoring the heap afterwards takes O(log n).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(binary_heap_remove_if)]
+    ///
+    /// use std::collections::BinaryHeap;
+    /// let mut heap = BinaryHeap::from(vec![1, 5, 2, 4]);
+    ///
+    /// assert_eq!(heap.remove_if(|&x| x == 2), Some(2));
+    /// assert_eq!(heap.remove_if(|&x| x == 3), None);
+    /// assert_eq!(heap.into_sorted_vec(), [1, 4, 5]);
+    /// ```
+    #[unstable(feature = "binary_heap_remove_if",
+               reason = "may be generalized to a `retain` or draining filter",
+               issue = "0")]
+    pub fn remove_if<F>(&mut self, mut pred: F) -> Option<T>
+        where F: FnMut(&T) -> bool
+    {
+        let pos = self.data.iter().position(|e| pred(e))?;
+        let removed = self.data.swap_remove(pos);
+        // The former last element now sits at `pos` and may belong either
+        // above or below it.
+        if pos < self.len() {
+            let pos = self.sift_up(0, pos);
+            self.sift_down(pos);
+        }
+        Some(removed)
+    }
+
     /// Clears the binary heap, returning an iterator over the removed elements.
     ///
     /// The elements are removed in arbitrary order.
@@ -796,7 +916,34 @@ impl<T: Ord> BinaryHeap<T> {
         self.drain();
     }
 
-    fn rebuild(&mut self) {
+    /// Restores the heap property over the whole backing storage in O(n).
+    ///
+    /// This is intended for use after elements have been modified in place
+    /// through [`as_mut_slice`]; it is cheaper than draining the heap and
+    /// pushing every element back.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(binary_heap_rebuild)]
+    /// use std::collections::BinaryHeap;
+    /// let mut heap = BinaryHeap::from(vec![1, 2, 3]);
+    ///
+    /// for x in heap.as_mut_slice() {
+    ///     *x = 10 - *x;
+    /// }
+    /// heap.rebuild();
+    ///
+    /// assert_eq!(heap.into_sorted_vec(), [7, 8, 9]);
+    /// ```
+    ///
+    /// [`as_mut_slice`]: #method.as_mut_slice
+    #[unstable(feature = "binary_heap_rebuild",
+               reason = "exposing the backing slice breaks the heap invariant until `rebuild`",
+               issue = "0")]
+    pub fn rebuild(&mut self) {
         let mut n = self.len() / 2;
         while n > 0 {
             n -= 1;
@@ -1047,6 +1194,60 @@ impl<T> ExactSizeIterator for IntoIter<T> {
 #[stable(feature = "fused", since = "1.26.0")]
 impl<T> FusedIterator for IntoIter<T> {}
 
+/// An owning iterator over the elements of a `BinaryHeap`, in heap order.
+///
+/// This `struct` is created by the [`into_iter_sorted`] method on
+/// [`BinaryHeap`]. See its documentation for more.
+///
+/// [`into_iter_sorted`]: struct.BinaryHeap.html#method.into_iter_sorted
+/// [`BinaryHeap`]: struct.BinaryHeap.html
+#[unstable(feature = "binary_heap_into_iter_sorted",
+           reason = "may be merged with `into_sorted_vec` or `drain_sorted`",
+           issue = "0")]
+#[derive(Clone)]
+pub struct IntoIterSorted<T> {
+    inner: BinaryHeap<T>,
+}
+
+#[unstable(feature = "binary_heap_into_iter_sorted",
+           reason = "may be merged with `into_sorted_vec` or `drain_sorted`",
+           issue = "0")]
+impl<T: fmt::Debug> fmt::Debug for IntoIterSorted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("IntoIterSorted")
+         .field(&self.inner.data)
+         .finish()
+    }
+}
+
+#[unstable(feature = "binary_heap_into_iter_sorted",
+           reason = "may be merged with `into_sorted_vec` or `drain_sorted`",
+           issue = "0")]
+impl<T: Ord> Iterator for IntoIterSorted<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact = self.inner.len();
+        (exact, Some(exact))
+    }
+}
+
+#[unstable(feature = "binary_heap_into_iter_sorted",
+           reason = "may be merged with `into_sorted_vec` or `drain_sorted`",
+           issue = "0")]
+impl<T: Ord> ExactSizeIterator for IntoIterSorted<T> {}
+
+#[unstable(feature = "binary_heap_into_iter_sorted",
+           reason = "may be merged with `into_sorted_vec` or `drain_sorted`",
+           issue = "0")]
+impl<T: Ord> FusedIterator for IntoIterSorted<T> {}
+
 /// A draining iterator over the elements of a `BinaryHeap`.
 ///
 /// This `struct` is created by the [`drain`] method on [`BinaryHeap`]. See its
@@ -659,6 +659,178 @@ impl<K: Ord, V> BTreeMap<K, V> {
         }
     }
 
+    /// Returns the first key-value pair in the map, i.e. the one with the
+    /// minimum key, or `None` if the map is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// assert_eq!(map.first_key_value(), None);
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    /// assert_eq!(map.first_key_value(), Some((&1, &"a")));
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        first_leaf_edge(self.root.as_ref()).right_kv().ok().map(|kv| kv.into_kv())
+    }
+
+    /// Returns the last key-value pair in the map, i.e. the one with the
+    /// maximum key, or `None` if the map is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// assert_eq!(map.last_key_value(), None);
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// assert_eq!(map.last_key_value(), Some((&2, &"b")));
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        last_leaf_edge(self.root.as_ref()).left_kv().ok().map(|kv| kv.into_kv())
+    }
+
+    /// Returns the entry for the first key in the map, i.e. the minimum key,
+    /// or `None` if the map is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// if let Some(mut entry) = map.first_entry() {
+    ///     *entry.get_mut() = "first";
+    /// }
+    /// assert_eq!(map[&1], "first");
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn first_entry(&mut self) -> Option<OccupiedEntry<K, V>> {
+        match first_leaf_edge(self.root.as_mut()).right_kv() {
+            Ok(kv) => Some(OccupiedEntry {
+                handle: kv.forget_node_type(),
+                length: &mut self.length,
+                _marker: PhantomData,
+            }),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the entry for the last key in the map, i.e. the maximum key,
+    /// or `None` if the map is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// if let Some(entry) = map.last_entry() {
+    ///     assert_eq!(entry.remove(), "b");
+    /// }
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn last_entry(&mut self) -> Option<OccupiedEntry<K, V>> {
+        match last_leaf_edge(self.root.as_mut()).left_kv() {
+            Ok(kv) => Some(OccupiedEntry {
+                handle: kv.forget_node_type(),
+                length: &mut self.length,
+                _marker: PhantomData,
+            }),
+            Err(_) => None,
+        }
+    }
+
+    /// Removes and returns the first element in the map.
+    /// The key of this element is the minimum key that was in the map.
+    ///
+    /// # Examples
+    ///
+    /// Draining elements in ascending order, while keeping a usable map each iteration.
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// while let Some((key, _val)) = map.pop_first() {
+    ///     assert!(map.iter().all(|(k, _v)| *k > key));
+    /// }
+    /// assert!(map.is_empty());
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        self.first_entry().map(|entry| entry.remove_entry())
+    }
+
+    /// Removes and returns the last element in the map.
+    /// The key of this element is the maximum key that was in the map.
+    ///
+    /// # Examples
+    ///
+    /// Draining elements in descending order, while keeping a usable map each iteration.
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// while let Some((key, _val)) = map.pop_last() {
+    ///     assert!(map.iter().all(|(k, _v)| *k < key));
+    /// }
+    /// assert!(map.is_empty());
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.last_entry().map(|entry| entry.remove_entry())
+    }
+
     /// Inserts a key-value pair into the map.
     ///
     /// If the map did not have this key present, `None` is returned.
@@ -786,6 +958,47 @@ impl<K: Ord, V> BTreeMap<K, V> {
         self.fix_right_edge();
     }
 
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(&k, &mut v)` returns `false`.
+    /// The predicate is given mutable access to each value, so kept values can be
+    /// updated in the same pass. Elements are visited in ascending key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(btree_retain)]
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut map: BTreeMap<i32, i32> = (0..8).map(|x| (x, x * 10)).collect();
+    /// map.retain(|&k, v| { *v += 1; k % 2 == 0 });
+    /// assert_eq!(map.into_iter().collect::<Vec<_>>(), [(0, 1), (2, 21), (4, 41), (6, 61)]);
+    /// ```
+    #[unstable(feature = "btree_retain",
+               reason = "recently added, may be generalized to a draining filter",
+               issue = "0")]
+    pub fn retain<F>(&mut self, mut f: F)
+        where F: FnMut(&K, &mut V) -> bool
+    {
+        let length = &mut self.length;
+        let mut edge = first_leaf_edge(self.root.as_mut());
+        // The tree is only modified after `f` returns, so a panicking
+        // predicate leaves a valid map behind.
+        while let Some(mut kv) = next_kv(edge) {
+            let keep = {
+                let (k, v) = kv.kv_mut();
+                f(k, v)
+            };
+            edge = if keep {
+                next_leaf_edge(kv)
+            } else {
+                *length -= 1;
+                let (_, next) = remove_kv_tracking(kv);
+                next
+            };
+        }
+    }
+
     /// Constructs a double-ended iterator over a sub-range of elements in the map.
     /// The simplest way is to use the range syntax `min..max`, thus `range(min..max)` will
     /// yield elements from min (inclusive) to max (exclusive).
@@ -2444,48 +2657,136 @@ impl<'a, K: Ord, V> OccupiedEntry<'a, K, V> {
     fn remove_kv(self) -> (K, V) {
         *self.length -= 1;
 
-        let (small_leaf, old_key, old_val) = match self.handle.force() {
-            Leaf(leaf) => {
-                let (hole, old_key, old_val) = leaf.remove();
-                (hole.into_node(), old_key, old_val)
-            }
-            Internal(mut internal) => {
-                let key_loc = internal.kv_mut().0 as *mut K;
-                let val_loc = internal.kv_mut().1 as *mut V;
-
-                let to_remove = first_leaf_edge(internal.right_edge().descend()).right_kv().ok();
-                let to_remove = unsafe { unwrap_unchecked(to_remove) };
+        let (old_kv, _) = remove_kv_tracking(self.handle);
+        old_kv
+    }
+}
 
-                let (hole, key, val) = to_remove.remove();
+/// Returns the key/value pair immediately to the right of a leaf edge, if any.
+fn next_kv<'a, K, V>(edge: Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::Edge>)
+                     -> Option<Handle<NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal>,
+                                      marker::KV>> {
+    let mut edge = match edge.right_kv() {
+        Ok(kv) => return Some(kv.forget_node_type()),
+        Err(last_edge) => match last_edge.into_node().forget_type().ascend() {
+            Ok(parent_edge) => parent_edge,
+            Err(_) => return None,
+        },
+    };
+    loop {
+        edge = match edge.right_kv() {
+            Ok(kv) => return Some(kv.forget_node_type()),
+            Err(last_edge) => match last_edge.into_node().forget_type().ascend() {
+                Ok(parent_edge) => parent_edge,
+                Err(_) => return None,
+            },
+        };
+    }
+}
 
-                let old_key = unsafe { mem::replace(&mut *key_loc, key) };
-                let old_val = unsafe { mem::replace(&mut *val_loc, val) };
+/// Returns the leaf edge immediately to the right of a key/value pair.
+fn next_leaf_edge<'a, K, V>(kv: Handle<NodeRef<marker::Mut<'a>, K, V, marker::LeafOrInternal>,
+                                       marker::KV>)
+                            -> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, marker::Edge> {
+    match kv.force() {
+        Leaf(leaf_kv) => leaf_kv.right_edge(),
+        Internal(internal_kv) => first_leaf_edge(internal_kv.right_edge().descend()),
+    }
+}
+
+/// Removes a key/value pair from the tree and rebalances it, returning the
+/// pair along with the leaf edge where the next pair in order now starts, so
+/// that a traversal can carry on after the tree has been rebalanced.
+fn remove_kv_tracking<'a, K, V>(kv: Handle<NodeRef<marker::Mut<'a>, K, V,
+                                                   marker::LeafOrInternal>,
+                                           marker::KV>)
+                                -> ((K, V),
+                                    Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>,
+                                           marker::Edge>) {
+    match kv.force() {
+        Leaf(leaf_kv) => remove_leaf_kv_tracking(leaf_kv),
+        Internal(internal_kv) => {
+            // Take the predecessor out of its leaf and put it in place of the
+            // pair we were asked to remove. Rebalancing may move that pair
+            // around, but it is always the first one after the hole.
+            let pred = last_leaf_edge(internal_kv.left_edge().descend()).left_kv().ok();
+            let pred = unsafe { unwrap_unchecked(pred) };
+            let ((pred_key, pred_val), hole) = remove_leaf_kv_tracking(pred);
+            let mut kv = unsafe { unwrap_unchecked(next_kv(hole)) };
+            let old_key = mem::replace(kv.kv_mut().0, pred_key);
+            let old_val = mem::replace(kv.kv_mut().1, pred_val);
+            ((old_key, old_val), next_leaf_edge(kv))
+        }
+    }
+}
+
+fn remove_leaf_kv_tracking<'a, K, V>(kv: Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>,
+                                                marker::KV>)
+                                     -> ((K, V),
+                                         Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>,
+                                                marker::Edge>) {
+    let (hole, key, val) = kv.remove();
+    if hole.reborrow().into_node().len() >= node::CAPACITY / 2 {
+        return ((key, val), hole);
+    }
+
+    // Handle underflow of the leaf itself, keeping track of where the hole
+    // ends up when the leaf is merged into or stolen from.
+    let idx = hole.idx();
+    let leaf = hole.into_node();
+    let parent_edge = match unsafe { ptr::read(&leaf) }.forget_type().ascend() {
+        Ok(parent_edge) => parent_edge,
+        Err(_) => return ((key, val), Handle::new_edge(leaf, idx)),
+    };
+    let (is_left, mut parent_kv) = match parent_edge.left_kv() {
+        Ok(left) => (true, left),
+        Err(parent_edge) => match parent_edge.right_kv() {
+            Ok(right) => (false, right),
+            Err(_) => unreachable!(),
+        },
+    };
 
-                (hole.into_node(), old_key, old_val)
-            }
+    if !parent_kv.can_merge() {
+        let idx = if is_left {
+            parent_kv.steal_left();
+            idx + 1
+        } else {
+            parent_kv.steal_right();
+            idx
         };
+        return ((key, val), Handle::new_edge(leaf, idx));
+    }
 
-        // Handle underflow
-        let mut cur_node = small_leaf.forget_type();
-        while cur_node.len() < node::CAPACITY / 2 {
-            match handle_underfull_node(cur_node) {
-                AtRoot => break,
-                EmptyParent(_) => unreachable!(),
-                Merged(parent) => {
-                    if parent.len() == 0 {
-                        // We must be at the root
-                        parent.into_root_mut().pop_level();
-                        break;
-                    } else {
-                        cur_node = parent.forget_type();
-                    }
-                }
-                Stole(_) => break,
-            }
-        }
+    let (mut cur_node, pos) = if is_left {
+        // The leaf is appended to its left sibling, after the separating pair.
+        let left_len = unsafe { ptr::read(&parent_kv) }.left_edge().descend().len();
+        let merged = parent_kv.merge();
+        // The merged child is a leaf, so this only recovers its static type.
+        let left = first_leaf_edge(unsafe { ptr::read(&merged) }.descend()).into_node();
+        (merged.into_node(), Handle::new_edge(left, left_len + 1 + idx))
+    } else {
+        (parent_kv.merge().into_node(), Handle::new_edge(leaf, idx))
+    };
 
-        (old_key, old_val)
+    // Handle underflow of the ancestors; this never moves pairs within the leaf.
+    loop {
+        if cur_node.len() == 0 {
+            // We must be at the root
+            cur_node.into_root_mut().pop_level();
+            break;
+        }
+        if cur_node.len() >= node::CAPACITY / 2 {
+            break;
+        }
+        match handle_underfull_node(cur_node.forget_type()) {
+            AtRoot => break,
+            EmptyParent(_) => unreachable!(),
+            Merged(parent) => cur_node = parent,
+            Stole(_) => break,
+        }
     }
+
+    ((key, val), pos)
 }
 
 enum UnderflowResult<'a, K, V> {
@@ -766,6 +766,11 @@ impl<Node, Type> Handle<Node, Type> {
     pub fn into_node(self) -> Node {
         self.node
     }
+
+    /// Returns the position of this handle in the node.
+    pub fn idx(&self) -> usize {
+        self.idx
+    }
 }
 
 impl<BorrowType, K, V, NodeType> Handle<NodeRef<BorrowType, K, V, NodeType>, marker::KV> {
@@ -788,6 +793,13 @@ impl<BorrowType, K, V, NodeType> Handle<NodeRef<BorrowType, K, V, NodeType>, mar
     pub fn right_edge(self) -> Handle<NodeRef<BorrowType, K, V, NodeType>, marker::Edge> {
         Handle::new_edge(self.node, self.idx + 1)
     }
+
+    /// Removes any static information about whether the node containing this
+    /// key/value pair is a `Leaf` or an `Internal` node.
+    pub fn forget_node_type(self)
+            -> Handle<NodeRef<BorrowType, K, V, marker::LeafOrInternal>, marker::KV> {
+        Handle::new_kv(self.node.forget_type(), self.idx)
+    }
 }
 
 impl<BorrowType, K, V, NodeType, HandleType> PartialEq
@@ -432,6 +432,102 @@ impl<T: Ord> BTreeSet<T> {
         Recover::get(&self.map, value)
     }
 
+    /// Returns a reference to the first value in the set, if any.
+    /// This value is always the minimum of all values in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set = BTreeSet::new();
+    /// assert_eq!(set.first(), None);
+    /// set.insert(2);
+    /// set.insert(1);
+    /// assert_eq!(set.first(), Some(&1));
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn first(&self) -> Option<&T> {
+        self.map.first_key_value().map(|(k, _)| k)
+    }
+
+    /// Returns a reference to the last value in the set, if any.
+    /// This value is always the maximum of all values in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set = BTreeSet::new();
+    /// assert_eq!(set.last(), None);
+    /// set.insert(1);
+    /// set.insert(2);
+    /// assert_eq!(set.last(), Some(&2));
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn last(&self) -> Option<&T> {
+        self.map.last_key_value().map(|(k, _)| k)
+    }
+
+    /// Removes the first value from the set and returns it, if any.
+    /// The first value is always the minimum value in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set: BTreeSet<_> = [3, 1, 2].iter().cloned().collect();
+    /// let mut drained = Vec::new();
+    /// while let Some(n) = set.pop_first() {
+    ///     drained.push(n);
+    /// }
+    /// assert_eq!(drained, [1, 2, 3]);
+    /// assert!(set.is_empty());
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.map.pop_first().map(|kv| kv.0)
+    }
+
+    /// Removes the last value from the set and returns it, if any.
+    /// The last value is always the maximum value in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(map_first_last)]
+    ///
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut set: BTreeSet<_> = [3, 1, 2].iter().cloned().collect();
+    /// let mut drained = Vec::new();
+    /// while let Some(n) = set.pop_last() {
+    ///     drained.push(n);
+    /// }
+    /// assert_eq!(drained, [3, 2, 1]);
+    /// assert!(set.is_empty());
+    /// ```
+    #[unstable(feature = "map_first_last",
+               reason = "naming and the set of provided methods are not yet settled",
+               issue = "0")]
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.map.pop_last().map(|kv| kv.0)
+    }
+
     /// Returns `true` if `self` has no elements in common with `other`.
     /// This is equivalent to checking for an empty intersection.
     ///
@@ -30,7 +30,9 @@ use core::marker::PhantomData;
 use core::mem;
 use core::ptr::NonNull;
 
+use binary_heap::BinaryHeap;
 use boxed::Box;
+use vec::Vec;
 use super::SpecExtend;
 
 /// A doubly-linked list with owned nodes.
@@ -134,6 +136,38 @@ impl<T: fmt::Debug> fmt::Debug for IntoIter<T> {
     }
 }
 
+/// A cursor over a `LinkedList` that can move in both directions and edit
+/// the list in place.
+///
+/// A cursor always rests either on an element of the list or on a "ghost"
+/// position that sits between the tail and the head. Moving forward from
+/// the tail or backward from the head reaches the ghost position, and moving
+/// from the ghost wraps around to the head or tail respectively.
+///
+/// This `struct` is created by the [`cursor`] method on [`LinkedList`]. See
+/// its documentation for more.
+///
+/// [`cursor`]: struct.LinkedList.html#method.cursor
+/// [`LinkedList`]: struct.LinkedList.html
+#[unstable(feature = "linked_list_cursor",
+           reason = "the cursor design is an experiment and likely to change",
+           issue = "0")]
+pub struct Cursor<'a, T: 'a> {
+    list: &'a mut LinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+}
+
+#[unstable(feature = "linked_list_cursor",
+           reason = "the cursor design is an experiment and likely to change",
+           issue = "0")]
+impl<'a, T: 'a + fmt::Debug> fmt::Debug for Cursor<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Cursor")
+         .field(&self.list)
+         .finish()
+    }
+}
+
 impl<T> Node<T> {
     fn new(element: T) -> Self {
         Node {
@@ -320,6 +354,33 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Prepends the elements of `iter` to the list, preserving their order.
+    ///
+    /// The first element yielded by the iterator ends up at the front of
+    /// the list, followed by the rest in iteration order, followed by the
+    /// previous contents of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(extend_front)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![4, 5].into_iter().collect();
+    /// list.extend_front(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    /// ```
+    #[unstable(feature = "extend_front",
+               reason = "the resulting order of the prepended elements is under discussion",
+               issue = "0")]
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut front: LinkedList<T> = iter.into_iter().collect();
+        front.append(self);
+        mem::swap(self, &mut front);
+    }
+
     /// Provides a forward iterator.
     ///
     /// # Examples
@@ -481,6 +542,140 @@ impl<T> LinkedList<T> {
         self.iter().any(|e| e == x)
     }
 
+    /// Checks the internal invariants of the list, panicking if any of them
+    /// are violated.
+    ///
+    /// This walks every node, verifying that each `prev` link points back at
+    /// the node it was reached from, that the tail is the last node reachable
+    /// from the head, and that the cached length matches the number of
+    /// nodes. It takes O(n) time and is intended for use in tests of code
+    /// that manipulates lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_assert_valid)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = (0..5).collect();
+    /// let mut tail = list.split_off(2);
+    /// tail.push_front(10);
+    /// list.append(&mut tail);
+    ///
+    /// list.assert_valid();
+    /// ```
+    #[unstable(feature = "linked_list_assert_valid",
+               reason = "debugging aid; the set of checked invariants may grow",
+               issue = "0")]
+    pub fn assert_valid(&self) {
+        unsafe {
+            let mut len = 0;
+            let mut last_ptr: Option<&Node<T>> = None;
+            let mut node_ptr: &Node<T>;
+            match self.head {
+                None => {
+                    // tail node should also be None.
+                    assert!(self.tail.is_none());
+                    assert_eq!(0, self.len);
+                    return;
+                }
+                Some(node) => node_ptr = &*node.as_ptr(),
+            }
+            loop {
+                match (last_ptr, node_ptr.prev) {
+                    (None, None) => {}
+                    (None, _) => panic!("prev link for head"),
+                    (Some(p), Some(pptr)) => {
+                        assert_eq!(p as *const Node<T>, pptr.as_ptr() as *const Node<T>);
+                    }
+                    _ => panic!("prev link is none, not good"),
+                }
+                match node_ptr.next {
+                    Some(next) => {
+                        last_ptr = Some(node_ptr);
+                        node_ptr = &*next.as_ptr();
+                        len += 1;
+                    }
+                    None => {
+                        len += 1;
+                        break;
+                    }
+                }
+            }
+
+            // verify that the tail node points to the last node.
+            let tail = self.tail.as_ref().expect("some tail node").as_ref();
+            assert_eq!(tail as *const Node<T>, node_ptr as *const Node<T>);
+            // check that len matches interior links.
+            assert_eq!(len, self.len);
+        }
+    }
+
+    /// Clones the elements of the list into a `Vec`, in back-to-front order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_to_vec_rev)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let list: LinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(list.to_vec_rev(), [3, 2, 1]);
+    /// ```
+    #[unstable(feature = "linked_list_to_vec_rev",
+               reason = "may be replaced by `iter().rev().cloned().collect()`",
+               issue = "0")]
+    pub fn to_vec_rev(&self) -> Vec<T>
+        where T: Clone
+    {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self.iter().rev().cloned());
+        vec
+    }
+
+    /// Moves the elements of the list into a `Vec`, in front-to-back order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_into_vec)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let list = LinkedList::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(list.into_vec(), [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "linked_list_into_vec",
+               reason = "niche conversion, kept unstable pending demand",
+               issue = "0")]
+    pub fn into_vec(self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self);
+        vec
+    }
+
+    /// Creates a list holding the elements of `vec`, in the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_into_vec)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let list = LinkedList::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(list.front(), Some(&1));
+    /// assert_eq!(list.back(), Some(&3));
+    /// ```
+    #[unstable(feature = "linked_list_into_vec",
+               reason = "niche conversion, kept unstable pending demand",
+               issue = "0")]
+    pub fn from_vec(vec: Vec<T>) -> LinkedList<T> {
+        vec.into_iter().collect()
+    }
+
     /// Provides a reference to the front element, or `None` if the list is
     /// empty.
     ///
@@ -581,6 +776,78 @@ impl<T> LinkedList<T> {
         }
     }
 
+    /// Provides a reference to the element at index `n` counting from the
+    /// front, or `None` if `n` is out of bounds.
+    ///
+    /// The list is walked from whichever end is nearer, so this operation
+    /// should compute in O(min(n, len - n)) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_nth)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let list: LinkedList<_> = vec!['a', 'b', 'c'].into_iter().collect();
+    /// assert_eq!(list.nth(0), Some(&'a'));
+    /// assert_eq!(list.nth(2), Some(&'c'));
+    /// assert_eq!(list.nth(3), None);
+    /// ```
+    #[unstable(feature = "linked_list_nth",
+               reason = "O(n) access may be better served by cursors",
+               issue = "0")]
+    pub fn nth(&self, n: usize) -> Option<&T> {
+        if n >= self.len {
+            return None;
+        }
+        unsafe {
+            let node = if n <= self.len / 2 {
+                let mut node = self.head;
+                for _ in 0..n {
+                    node = node.and_then(|node| node.as_ref().next);
+                }
+                node
+            } else {
+                let mut node = self.tail;
+                for _ in 0..self.len - 1 - n {
+                    node = node.and_then(|node| node.as_ref().prev);
+                }
+                node
+            };
+            node.map(|node| &(*node.as_ptr()).element)
+        }
+    }
+
+    /// Provides a reference to the element at index `n` counting from the
+    /// back, or `None` if `n` is out of bounds.
+    ///
+    /// Like [`nth`], this walks from whichever end is nearer.
+    ///
+    /// [`nth`]: #method.nth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_nth)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let list: LinkedList<_> = vec!['a', 'b', 'c'].into_iter().collect();
+    /// assert_eq!(list.nth_back(0), Some(&'c'));
+    /// assert_eq!(list.nth_back(2), Some(&'a'));
+    /// assert_eq!(list.nth_back(3), None);
+    /// ```
+    #[unstable(feature = "linked_list_nth",
+               reason = "O(n) access may be better served by cursors",
+               issue = "0")]
+    pub fn nth_back(&self, n: usize) -> Option<&T> {
+        if n >= self.len {
+            return None;
+        }
+        self.nth(self.len - 1 - n)
+    }
+
     /// Adds an element first in the list.
     ///
     /// This operation should compute in O(1) time.
@@ -663,6 +930,68 @@ impl<T> LinkedList<T> {
         self.pop_back_node().map(Node::into_element)
     }
 
+    /// Removes the first element and returns it if `pred` returns `true` for
+    /// it. Otherwise, or if the list is empty, the list is left untouched and
+    /// `None` is returned.
+    ///
+    /// This operation should compute in O(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_pop_if)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut d: LinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(d.pop_front_if(|&x| x > 1), None);
+    /// assert_eq!(d.pop_front_if(|&x| x == 1), Some(1));
+    /// assert_eq!(d.front(), Some(&2));
+    /// ```
+    #[unstable(feature = "linked_list_pop_if",
+               reason = "may be superseded by cursor-based removal",
+               issue = "0")]
+    pub fn pop_front_if<F>(&mut self, pred: F) -> Option<T>
+        where F: FnOnce(&T) -> bool
+    {
+        if self.front().map_or(false, pred) {
+            self.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Removes the last element and returns it if `pred` returns `true` for
+    /// it. Otherwise, or if the list is empty, the list is left untouched and
+    /// `None` is returned.
+    ///
+    /// This operation should compute in O(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_pop_if)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut d: LinkedList<_> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(d.pop_back_if(|&x| x < 3), None);
+    /// assert_eq!(d.pop_back_if(|&x| x == 3), Some(3));
+    /// assert_eq!(d.back(), Some(&2));
+    /// ```
+    #[unstable(feature = "linked_list_pop_if",
+               reason = "may be superseded by cursor-based removal",
+               issue = "0")]
+    pub fn pop_back_if<F>(&mut self, pred: F) -> Option<T>
+        where F: FnOnce(&T) -> bool
+    {
+        if self.back().map_or(false, pred) {
+            self.pop_back()
+        } else {
+            None
+        }
+    }
+
     /// Splits the list into two at the given index. Returns everything after the given index,
     /// including the index.
     ///
@@ -785,8 +1114,125 @@ impl<T> LinkedList<T> {
             old_len: old_len,
         }
     }
+
+    /// Provides a cursor positioned at the "ghost" position between the tail
+    /// and the head of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_cursor)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![1, 2, 4].into_iter().collect();
+    ///
+    /// {
+    ///     let mut cursor = list.cursor();
+    ///     cursor.move_prev();
+    ///     assert_eq!(cursor.current(), Some(&mut 4));
+    ///     cursor.insert_before(3);
+    ///     cursor.move_next();
+    ///     assert_eq!(cursor.current(), None);
+    ///     cursor.move_next();
+    ///     assert_eq!(cursor.remove_current(), Some(1));
+    /// }
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [2, 3, 4]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "linked_list_cursor",
+               reason = "the cursor design is an experiment and likely to change",
+               issue = "0")]
+    pub fn cursor(&mut self) -> Cursor<T> {
+        Cursor {
+            list: self,
+            current: None,
+        }
+    }
+}
+
+/// Merges several sorted lists into a single sorted list.
+///
+/// Each list in `lists` must already be sorted in ascending order. The nodes
+/// of the input lists are reused, so no elements are moved or reallocated.
+/// Equal elements keep the order of the lists they came from.
+///
+/// This operation should compute in O(n log k) time, where `n` is the total
+/// number of elements and `k` the number of lists.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(linked_list_merge_all)]
+/// use std::collections::LinkedList;
+/// use std::collections::linked_list::merge_all;
+///
+/// let a: LinkedList<_> = vec![1, 4].into_iter().collect();
+/// let b: LinkedList<_> = vec![2, 3].into_iter().collect();
+/// let c: LinkedList<_> = vec![5].into_iter().collect();
+///
+/// let merged = merge_all(vec![a, b, c]);
+/// assert_eq!(merged.into_iter().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+/// ```
+#[unstable(feature = "linked_list_merge_all",
+           reason = "may become a method or accept any iterator of lists",
+           issue = "0")]
+pub fn merge_all<T: Ord>(lists: Vec<LinkedList<T>>) -> LinkedList<T> {
+    let mut heads: BinaryHeap<_> = lists.into_iter()
+        .enumerate()
+        .filter(|&(_, ref list)| !list.is_empty())
+        .map(|(index, list)| MergeHead { list, index })
+        .collect();
+
+    let mut merged = LinkedList::new();
+    while let Some(mut head) = heads.pop() {
+        if heads.is_empty() {
+            // Only one list is left, so its remaining nodes can be moved
+            // over all at once.
+            merged.append(&mut head.list);
+            break;
+        }
+        let node = head.list.pop_front_node().unwrap();
+        merged.push_back_node(node);
+        if !head.list.is_empty() {
+            heads.push(head);
+        }
+    }
+    merged
+}
+
+/// A non-empty list waiting to be merged by `merge_all`.
+///
+/// `BinaryHeap` is a max-heap, so heads are ordered in reverse: the list with
+/// the smallest front element, and among those the one that came first, is
+/// the greatest.
+struct MergeHead<T> {
+    list: LinkedList<T>,
+    index: usize,
+}
+
+impl<T: Ord> Ord for MergeHead<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.list.front().cmp(&self.list.front())
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+impl<T: Ord> PartialOrd for MergeHead<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
+impl<T: Ord> PartialEq for MergeHead<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Ord> Eq for MergeHead<T> {}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 unsafe impl<#[may_dangle] T> Drop for LinkedList<T> {
     fn drop(&mut self) {
@@ -973,6 +1419,171 @@ impl<'a, T> IterMut<'a, T> {
             }
         }
     }
+
+    /// Inserts the given element just after the element most recently returned by `.next()`,
+    /// so that it is the next element returned by the iterator.
+    ///
+    /// This differs from [`insert_next`] only in that the inserted element does appear in the
+    /// rest of the iteration.
+    ///
+    /// [`insert_next`]: #method.insert_next
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(linked_list_extras)]
+    ///
+    /// use std::collections::LinkedList;
+    ///
+    /// let mut list: LinkedList<_> = vec![1, 3].into_iter().collect();
+    ///
+    /// {
+    ///     let mut it = list.iter_mut();
+    ///     assert_eq!(it.next().unwrap(), &1);
+    ///     it.insert_prev(2);
+    ///     assert_eq!(it.next().unwrap(), &2);
+    ///     assert_eq!(it.next().unwrap(), &3);
+    ///     assert!(it.next().is_none());
+    /// }
+    ///
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "linked_list_extras",
+               reason = "this is probably better handled by a cursor type -- we'll see",
+               issue = "27794")]
+    pub fn insert_prev(&mut self, element: T) {
+        // `insert_next` links the new node in just before `self.head`, or at
+        // the back of the list if there is no such node.
+        self.insert_next(element);
+        let node = match self.head {
+            None => self.list.tail,
+            Some(head) => unsafe { head.as_ref().prev },
+        };
+        if self.len == 0 {
+            self.tail = node;
+        }
+        self.head = node;
+        self.len += 1;
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Moves the cursor to the next element of the list.
+    ///
+    /// If the cursor is on the ghost position this moves it to the head of
+    /// the list, and if it is on the tail it moves it to the ghost position.
+    #[unstable(feature = "linked_list_cursor",
+               reason = "the cursor design is an experiment and likely to change",
+               issue = "0")]
+    pub fn move_next(&mut self) {
+        self.current = match self.current {
+            None => self.list.head,
+            Some(current) => unsafe { current.as_ref().next },
+        };
+    }
+
+    /// Moves the cursor to the previous element of the list.
+    ///
+    /// If the cursor is on the ghost position this moves it to the tail of
+    /// the list, and if it is on the head it moves it to the ghost position.
+    #[unstable(feature = "linked_list_cursor",
+               reason = "the cursor design is an experiment and likely to change",
+               issue = "0")]
+    pub fn move_prev(&mut self) {
+        self.current = match self.current {
+            None => self.list.tail,
+            Some(current) => unsafe { current.as_ref().prev },
+        };
+    }
+
+    /// Returns a reference to the element the cursor is currently on, or
+    /// `None` if it is on the ghost position.
+    #[unstable(feature = "linked_list_cursor",
+               reason = "the cursor design is an experiment and likely to change",
+               issue = "0")]
+    pub fn current(&mut self) -> Option<&mut T> {
+        unsafe {
+            self.current.as_mut().map(|node| &mut node.as_mut().element)
+        }
+    }
+
+    /// Inserts an element just after the cursor, without moving it.
+    ///
+    /// If the cursor is on the ghost position the element becomes the new
+    /// head of the list.
+    #[unstable(feature = "linked_list_cursor",
+               reason = "the cursor design is an experiment and likely to change",
+               issue = "0")]
+    pub fn insert_after(&mut self, element: T) {
+        let current = self.current;
+        unsafe {
+            let next = match current {
+                None => self.list.head,
+                Some(current) => current.as_ref().next,
+            };
+            self.insert_between(current, next, element);
+        }
+    }
+
+    /// Inserts an element just before the cursor, without moving it.
+    ///
+    /// If the cursor is on the ghost position the element becomes the new
+    /// tail of the list.
+    #[unstable(feature = "linked_list_cursor",
+               reason = "the cursor design is an experiment and likely to change",
+               issue = "0")]
+    pub fn insert_before(&mut self, element: T) {
+        let current = self.current;
+        unsafe {
+            let prev = match current {
+                None => self.list.tail,
+                Some(current) => current.as_ref().prev,
+            };
+            self.insert_between(prev, current, element);
+        }
+    }
+
+    /// Removes the element the cursor is on and returns it, moving the
+    /// cursor to the following element.
+    ///
+    /// Returns `None`, and leaves the list unchanged, if the cursor is on
+    /// the ghost position.
+    #[unstable(feature = "linked_list_cursor",
+               reason = "the cursor design is an experiment and likely to change",
+               issue = "0")]
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+        unsafe {
+            self.current = node.as_ref().next;
+            self.list.unlink_node(node);
+            Some(Box::from_raw(node.as_ptr()).into_element())
+        }
+    }
+
+    /// Links a new node holding `element` between two adjacent nodes, where
+    /// `None` stands for the ends of the list.
+    unsafe fn insert_between(&mut self,
+                             prev: Option<NonNull<Node<T>>>,
+                             next: Option<NonNull<Node<T>>>,
+                             element: T) {
+        let node = Some(Box::into_raw_non_null(box Node {
+            next,
+            prev,
+            element,
+        }));
+
+        match prev {
+            Some(mut prev) => prev.as_mut().next = node,
+            None => self.list.head = node,
+        }
+        match next {
+            Some(mut next) => next.as_mut().prev = node,
+            None => self.list.tail = node,
+        }
+
+        self.list.len += 1;
+    }
 }
 
 /// An iterator produced by calling `drain_filter` on LinkedList.
@@ -1232,47 +1843,114 @@ mod tests {
     }
 
     pub fn check_links<T>(list: &LinkedList<T>) {
-        unsafe {
-            let mut len = 0;
-            let mut last_ptr: Option<&Node<T>> = None;
-            let mut node_ptr: &Node<T>;
-            match list.head {
-                None => {
-                    // tail node should also be None.
-                    assert!(list.tail.is_none());
-                    assert_eq!(0, list.len);
-                    return;
-                }
-                Some(node) => node_ptr = &*node.as_ptr(),
-            }
-            loop {
-                match (last_ptr, node_ptr.prev) {
-                    (None, None) => {}
-                    (None, _) => panic!("prev link for head"),
-                    (Some(p), Some(pptr)) => {
-                        assert_eq!(p as *const Node<T>, pptr.as_ptr() as *const Node<T>);
-                    }
-                    _ => panic!("prev link is none, not good"),
-                }
-                match node_ptr.next {
-                    Some(next) => {
-                        last_ptr = Some(node_ptr);
-                        node_ptr = &*next.as_ptr();
-                        len += 1;
-                    }
-                    None => {
-                        len += 1;
-                        break;
-                    }
-                }
-            }
+        list.assert_valid();
+    }
 
-            // verify that the tail node points to the last node.
-            let tail = list.tail.as_ref().expect("some tail node").as_ref();
-            assert_eq!(tail as *const Node<T>, node_ptr as *const Node<T>);
-            // check that len matches interior links.
-            assert_eq!(len, list.len);
+    #[test]
+    fn test_cursor() {
+        let mut list = list_from(&[1, 2, 3, 4]);
+        {
+            let mut cursor = list.cursor();
+            assert_eq!(cursor.current(), None);
+
+            // walk forward, then back up
+            cursor.move_next();
+            cursor.move_next();
+            cursor.move_next();
+            assert_eq!(cursor.current(), Some(&mut 3));
+            cursor.move_prev();
+            assert_eq!(cursor.current(), Some(&mut 2));
+
+            // delete, landing on the following element
+            assert_eq!(cursor.remove_current(), Some(2));
+            assert_eq!(cursor.current(), Some(&mut 3));
+
+            cursor.insert_before(10);
+            cursor.insert_after(20);
+            assert_eq!(cursor.current(), Some(&mut 3));
+            *cursor.current().unwrap() = 30;
+
+            // wrap around through the ghost position
+            cursor.move_next();
+            cursor.move_next();
+            cursor.move_next();
+            assert_eq!(cursor.current(), None);
+            assert_eq!(cursor.remove_current(), None);
+            cursor.insert_after(0);
+            cursor.insert_before(5);
+            cursor.move_prev();
+            assert_eq!(cursor.current(), Some(&mut 5));
         }
+        check_links(&list);
+        assert_eq!(list.len(), 7);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), [0, 1, 10, 30, 20, 4, 5]);
+    }
+
+    #[test]
+    fn test_cursor_drain() {
+        let mut list = list_from(&[1, 2, 3]);
+        {
+            let mut cursor = list.cursor();
+            cursor.move_prev();
+            assert_eq!(cursor.remove_current(), Some(3));
+            assert_eq!(cursor.current(), None);
+            cursor.move_next();
+            assert_eq!(cursor.remove_current(), Some(1));
+            assert_eq!(cursor.remove_current(), Some(2));
+            assert_eq!(cursor.remove_current(), None);
+        }
+        check_links(&list);
+        assert!(list.is_empty());
+
+        {
+            let mut cursor = list.cursor();
+            cursor.insert_before(1);
+            cursor.move_next();
+            assert_eq!(cursor.current(), Some(&mut 1));
+        }
+        check_links(&list);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn test_assert_valid() {
+        let mut list = list_from(&[1, 2, 3, 4]);
+        list.assert_valid();
+        let mut tail = list.split_off(1);
+        tail.push_front(0);
+        list.append(&mut tail);
+        list.assert_valid();
+        LinkedList::<i32>::new().assert_valid();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_valid_bad_len() {
+        let mut list = list_from(&[1, 2, 3]);
+        list.len += 1;
+        list.assert_valid();
+    }
+
+    #[test]
+    fn test_merge_all() {
+        let merged = super::merge_all(vec![list_from(&[1, 4]),
+                                           LinkedList::new(),
+                                           list_from(&[2, 3]),
+                                           list_from(&[5])]);
+        check_links(&merged);
+        assert_eq!(merged.iter().cloned().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+
+        let merged = super::merge_all(vec![list_from(&[1, 1, 7]), list_from(&[0, 1, 9])]);
+        check_links(&merged);
+        assert_eq!(merged.iter().cloned().collect::<Vec<_>>(), [0, 1, 1, 1, 7, 9]);
+
+        let merged = super::merge_all(Vec::<LinkedList<i32>>::new());
+        check_links(&merged);
+        assert!(merged.is_empty());
+
+        let merged = super::merge_all(vec![LinkedList::<i32>::new(), LinkedList::new()]);
+        check_links(&merged);
+        assert!(merged.is_empty());
     }
 
     #[test]
@@ -1332,6 +2010,74 @@ mod tests {
         check_links(&n);
     }
 
+    #[test]
+    fn test_into_vec_from_vec() {
+        let list = list_from(&[1, 2, 3]);
+        let vec = list.into_vec();
+        assert_eq!(vec, [1, 2, 3]);
+        let list = LinkedList::from_vec(vec);
+        check_links(&list);
+        assert_eq!(list, list_from(&[1, 2, 3]));
+
+        let empty = LinkedList::<i32>::from_vec(Vec::new());
+        check_links(&empty);
+        assert!(empty.into_vec().is_empty());
+    }
+
+    #[test]
+    fn test_pop_if() {
+        let mut m = list_from(&[1, 2, 3, 4]);
+        assert_eq!(m.pop_front_if(|&x| x == 2), None);
+        assert_eq!(m.pop_back_if(|&x| x == 3), None);
+        assert_eq!(m.len(), 4);
+        check_links(&m);
+
+        assert_eq!(m.pop_front_if(|&x| x == 1), Some(1));
+        assert_eq!(m.pop_back_if(|&x| x == 4), Some(4));
+        check_links(&m);
+        assert_eq!(m, list_from(&[2, 3]));
+
+        let mut empty = LinkedList::<i32>::new();
+        assert_eq!(empty.pop_front_if(|_| true), None);
+        assert_eq!(empty.pop_back_if(|_| true), None);
+    }
+
+    #[test]
+    fn test_nth() {
+        let v: Vec<i32> = (0..9).collect();
+        let m = list_from(&v);
+        for i in 0..9 {
+            assert_eq!(m.nth(i), Some(&v[i]));
+            assert_eq!(m.nth_back(i), Some(&v[8 - i]));
+        }
+        assert_eq!(m.nth(4), Some(&4));
+        assert_eq!(m.nth(9), None);
+        assert_eq!(m.nth_back(9), None);
+        assert_eq!(m.nth(usize::max_value()), None);
+
+        let empty = LinkedList::<i32>::new();
+        assert_eq!(empty.nth(0), None);
+        assert_eq!(empty.nth_back(0), None);
+    }
+
+    #[test]
+    fn test_append_reuse_scratch() {
+        let mut m = LinkedList::new();
+        let mut scratch = LinkedList::new();
+        for round in 0..2 {
+            scratch.push_back(round * 10);
+            scratch.push_back(round * 10 + 1);
+            check_links(&scratch);
+            m.append(&mut scratch);
+            check_links(&m);
+            check_links(&scratch);
+            assert!(scratch.is_empty());
+            assert_eq!(scratch.front(), None);
+            assert_eq!(scratch.back(), None);
+        }
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), [0, 1, 10, 11]);
+    }
+
     #[test]
     fn test_insert_prev() {
         let mut m = list_from(&[0, 2, 4, 6, 8]);
@@ -1360,6 +2106,44 @@ mod tests {
                    [-2, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1]);
     }
 
+    #[test]
+    fn test_iter_mut_insert_prev() {
+        let mut m = list_from(&[1, 3, 5]);
+        let mut seen = vec![];
+        {
+            let mut it = m.iter_mut();
+            it.insert_prev(0);
+            while let Some(elt) = it.next() {
+                seen.push(*elt);
+                if *elt % 2 == 1 {
+                    it.insert_prev(*elt + 1);
+                }
+            }
+            // at the end, the inserted element becomes the new tail
+            it.insert_prev(7);
+            assert_eq!(it.next(), Some(&mut 7));
+            assert_eq!(it.next(), None);
+        }
+        assert_eq!(seen, [0, 1, 2, 3, 4, 5, 6]);
+        check_links(&m);
+        assert_eq!(m.len(), 8);
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 6, 7]);
+
+        // iterating from both ends
+        let mut m = list_from(&[1, 2, 3]);
+        {
+            let mut it = m.iter_mut();
+            assert_eq!(it.next_back(), Some(&mut 3));
+            assert_eq!(it.next(), Some(&mut 1));
+            assert_eq!(it.next(), Some(&mut 2));
+            it.insert_prev(10);
+            assert_eq!(it.next_back(), Some(&mut 10));
+            assert_eq!(it.next(), None);
+        }
+        check_links(&m);
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), [1, 2, 10, 3]);
+    }
+
     #[test]
     #[cfg_attr(target_os = "emscripten", ignore)]
     fn test_send() {
@@ -1472,6 +2256,18 @@ mod tests {
         assert_eq!(m.into_iter().collect::<Vec<_>>(), &[4, 5, 6]);
     }
 
+    #[test]
+    fn drain_filter_interleaved_test() {
+        let mut m = list_from(&[1, 2, 3, 4, 5]);
+        let deleted = m.drain_filter(|v| *v % 2 == 1).collect::<Vec<_>>();
+
+        check_links(&m);
+
+        assert_eq!(deleted, &[1, 3, 5]);
+        assert_eq!(m.len(), 2);
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), &[2, 4]);
+    }
+
     #[test]
     fn drain_to_empty_test() {
         let mut m: LinkedList<u32> = LinkedList::new();
@@ -126,6 +126,10 @@ pub use core::slice::{from_ref, from_ref_mut};
 pub use core::slice::SliceIndex;
 #[unstable(feature = "exact_chunks", issue = "47115")]
 pub use core::slice::{ExactChunks, ExactChunksMut};
+#[unstable(feature = "rchunks",
+           reason = "the placement of the remainder chunk is still under discussion",
+           issue = "0")]
+pub use core::slice::RChunks;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Basic slice extension methods
@@ -609,6 +613,35 @@ impl<T> [T] {
         core_slice::SliceExt::windows(self, size)
     }
 
+    /// Calls `f` on each pair of adjacent elements, passing mutable
+    /// references to both.
+    ///
+    /// The pairs are visited from left to right: `f` is first called with
+    /// `(&mut self[0], &mut self[1])`, then `(&mut self[1], &mut self[2])`,
+    /// and so on, so changes made to the right element of one pair are seen
+    /// as the left element of the next. If the slice has fewer than two
+    /// elements, `f` is never called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_pairwise_mut)]
+    ///
+    /// // one pass of bubble sort
+    /// let mut v = [5, 1, 4, 2, 3];
+    /// v.pairwise_mut(|a, b| if *a > *b { ::std::mem::swap(a, b) });
+    /// assert_eq!(v, [1, 4, 2, 3, 5]);
+    /// ```
+    #[unstable(feature = "slice_pairwise_mut",
+               reason = "may be generalized to windows of arbitrary size",
+               issue = "0")]
+    #[inline]
+    pub fn pairwise_mut<F>(&mut self, f: F)
+        where F: FnMut(&mut T, &mut T)
+    {
+        core_slice::SliceExt::pairwise_mut(self, f)
+    }
+
     /// Returns an iterator over `chunk_size` elements of the slice at a
     /// time. The chunks are slices and do not overlap. If `chunk_size` does
     /// not divide the length of the slice, then the last chunk will
@@ -642,7 +675,8 @@ impl<T> [T] {
     /// Returns an iterator over `chunk_size` elements of the slice at a
     /// time. The chunks are slices and do not overlap. If `chunk_size` does
     /// not divide the length of the slice, then the last up to `chunk_size-1`
-    /// elements will be omitted.
+    /// elements will be omitted and can be retrieved from the `remainder`
+    /// function of the iterator.
     ///
     /// Due to each chunk having exactly `chunk_size` elements, the compiler
     /// can often optimize the resulting code better than in the case of
@@ -671,6 +705,36 @@ impl<T> [T] {
         core_slice::SliceExt::exact_chunks(self, chunk_size)
     }
 
+    /// Returns an iterator over `chunk_size` elements of the slice at a
+    /// time, starting at the end of the slice. The chunks are slices and do
+    /// not overlap. If `chunk_size` does not divide the length of the slice,
+    /// then the last chunk will not have length `chunk_size`, and will be
+    /// taken from the beginning of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(rchunks)]
+    ///
+    /// let slice = ['l', 'o', 'r', 'e', 'm'];
+    /// let mut iter = slice.rchunks(2);
+    /// assert_eq!(iter.next().unwrap(), &['e', 'm']);
+    /// assert_eq!(iter.next().unwrap(), &['o', 'r']);
+    /// assert_eq!(iter.next().unwrap(), &['l']);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[unstable(feature = "rchunks",
+               reason = "the placement of the remainder chunk is still under discussion",
+               issue = "0")]
+    #[inline]
+    pub fn rchunks(&self, chunk_size: usize) -> RChunks<T> {
+        core_slice::SliceExt::rchunks(self, chunk_size)
+    }
+
     /// Returns an iterator over `chunk_size` elements of the slice at a time.
     /// The chunks are mutable slices, and do not overlap. If `chunk_size` does
     /// not divide the length of the slice, then the last chunk will not
@@ -708,7 +772,8 @@ impl<T> [T] {
     /// Returns an iterator over `chunk_size` elements of the slice at a time.
     /// The chunks are mutable slices, and do not overlap. If `chunk_size` does
     /// not divide the length of the slice, then the last up to `chunk_size-1`
-    /// elements will be omitted.
+    /// elements will be omitted and can be retrieved from the `into_remainder`
+    /// function of the iterator.
     ///
     ///
     /// Due to each chunk having exactly `chunk_size` elements, the compiler
@@ -782,6 +847,33 @@ impl<T> [T] {
         core_slice::SliceExt::split_at(self, mid)
     }
 
+    /// Divides one slice into two at an index, returning `None` if the index
+    /// is out of bounds.
+    ///
+    /// This is the non-panicking version of [`split_at`]: if `mid <= len`,
+    /// the two halves `[0, mid)` and `[mid, len)` are returned, otherwise
+    /// `None`.
+    ///
+    /// [`split_at`]: #method.split_at
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_at_checked)]
+    /// let v = [1, 2, 3, 4, 5, 6];
+    ///
+    /// assert_eq!(v.split_at_checked(2), Some((&[1, 2][..], &[3, 4, 5, 6][..])));
+    /// assert_eq!(v.split_at_checked(6), Some((&v[..], &[][..])));
+    /// assert_eq!(v.split_at_checked(7), None);
+    /// ```
+    #[unstable(feature = "slice_split_at_checked",
+               reason = "may be replaced by a `get`-style range API",
+               issue = "0")]
+    #[inline]
+    pub fn split_at_checked(&self, mid: usize) -> Option<(&[T], &[T])> {
+        core_slice::SliceExt::split_at_checked(self, mid)
+    }
+
     /// Divides one mutable slice into two at an index.
     ///
     /// The first will contain all indices from `[0, mid)` (excluding
@@ -1751,6 +1843,46 @@ impl<T> [T] {
         core_slice::SliceExt::swap_with_slice(self, other)
     }
 
+    /// Fills `self` with elements by cloning `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_fill)]
+    ///
+    /// let mut buf = vec![0; 6];
+    /// buf[1..4].fill(7);
+    /// assert_eq!(buf, [0, 7, 7, 7, 0, 0]);
+    /// ```
+    #[unstable(feature = "slice_fill",
+               reason = "the `Clone` bound may be relaxed or specialized for `Copy`",
+               issue = "0")]
+    #[inline]
+    pub fn fill(&mut self, value: T) where T: Clone {
+        core_slice::SliceExt::fill(self, value)
+    }
+
+    /// Fills `self` with elements returned by calling `f` repeatedly, front
+    /// to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_fill)]
+    ///
+    /// let mut buf = vec![0; 5];
+    /// let mut next = 0;
+    /// buf.fill_with(|| { next += 2; next });
+    /// assert_eq!(buf, [2, 4, 6, 8, 10]);
+    /// ```
+    #[unstable(feature = "slice_fill",
+               reason = "the `Clone` bound may be relaxed or specialized for `Copy`",
+               issue = "0")]
+    #[inline]
+    pub fn fill_with<F>(&mut self, f: F) where F: FnMut() -> T {
+        core_slice::SliceExt::fill_with(self, f)
+    }
+
     /// Copies `self` into a new `Vec`.
     ///
     /// # Examples
@@ -1951,7 +2083,9 @@ impl<T: Clone, V: Borrow<[T]>> SliceConcatExt<T> for [V] {
 
     fn join(&self, sep: &T) -> Vec<T> {
         let size = self.iter().fold(0, |acc, v| acc + v.borrow().len());
-        let mut result = Vec::with_capacity(size + self.len());
+        // one separator between each pair of slices
+        let seps = self.len().saturating_sub(1);
+        let mut result = Vec::with_capacity(size + seps);
         let mut first = true;
         for v in self {
             if first {
@@ -76,6 +76,10 @@ pub use core::str::{from_utf8, from_utf8_mut, Chars, CharIndices, Bytes};
 pub use core::str::{from_utf8_unchecked, from_utf8_unchecked_mut, ParseBoolError};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use std_unicode::str::SplitWhitespace;
+#[unstable(feature = "str_byte_lines",
+           reason = "may be folded into a general byte-string API",
+           issue = "0")]
+pub use core::str::{byte_lines, ByteLines};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::pattern;
 
@@ -937,6 +941,52 @@ impl str {
         core_str::StrExt::ends_with(self, pat)
     }
 
+    /// Returns a string slice with the prefix removed.
+    ///
+    /// If the string starts with the pattern `prefix`, returns the substring
+    /// after the prefix, wrapped in `Some`. Otherwise, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_strip)]
+    ///
+    /// assert_eq!("foo:bar".strip_prefix("foo:"), Some("bar"));
+    /// assert_eq!("foo:bar".strip_prefix("bar"), None);
+    /// assert_eq!("foofoo".strip_prefix("foo"), Some("foo"));
+    /// ```
+    #[unstable(feature = "str_strip", reason = "newly added", issue = "0")]
+    #[inline]
+    pub fn strip_prefix<'a, P: Pattern<'a>>(&'a self, prefix: P) -> Option<&'a str> {
+        core_str::StrExt::strip_prefix(self, prefix)
+    }
+
+    /// Returns a string slice with the suffix removed.
+    ///
+    /// If the string ends with the pattern `suffix`, returns the substring
+    /// before the suffix, wrapped in `Some`. Otherwise, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_strip)]
+    ///
+    /// assert_eq!("bar:foo".strip_suffix(":foo"), Some("bar"));
+    /// assert_eq!("bar:foo".strip_suffix("bar"), None);
+    /// assert_eq!("foofoo".strip_suffix("foo"), Some("foo"));
+    /// ```
+    #[unstable(feature = "str_strip", reason = "newly added", issue = "0")]
+    #[inline]
+    pub fn strip_suffix<'a, P: Pattern<'a>>(&'a self, suffix: P) -> Option<&'a str>
+        where P::Searcher: ReverseSearcher<'a>
+    {
+        core_str::StrExt::strip_suffix(self, suffix)
+    }
+
     /// Returns the byte index of the first character of this string slice that
     /// matches the pattern.
     ///
@@ -1427,6 +1477,35 @@ impl str {
         core_str::StrExt::matches(self, pat)
     }
 
+    /// Returns the number of disjoint occurrences of `pat` in this string
+    /// slice.
+    ///
+    /// Matches are counted from left to right, resuming the search after the
+    /// end of each match, so overlapping occurrences are only counted once.
+    /// An empty pattern never matches and returns `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_count_matches)]
+    ///
+    /// assert_eq!("a,b,c".count_matches(","), 2);
+    /// assert_eq!("aaaa".count_matches("aa"), 2);
+    /// assert_eq!("abc".count_matches("d"), 0);
+    /// ```
+    #[unstable(feature = "str_count_matches",
+               reason = "may be subsumed by `matches().count()` specialization",
+               issue = "0")]
+    pub fn count_matches(&self, pat: &str) -> usize {
+        if pat.is_empty() {
+            0
+        } else {
+            self.matches(pat).count()
+        }
+    }
+
     /// An iterator over the disjoint matches of a pattern within this string slice,
     /// yielded in reverse order.
     ///
@@ -2122,6 +2201,45 @@ impl str {
         unsafe { String::from_utf8_unchecked(buf) }
     }
 
+    /// Returns a copy of this string slice shortened to at most `max_chars`
+    /// characters, ending in `'…'` if anything was cut off.
+    ///
+    /// If the string has more than `max_chars` characters, the result is its
+    /// first `max_chars - 1` characters followed by `'…'`. Otherwise the
+    /// string is copied unchanged. Lengths are counted in `char`s, so
+    /// multi-byte characters are never split.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(str_truncate_ellipsis)]
+    ///
+    /// assert_eq!("Hello, world".truncate_ellipsis(6), "Hello…");
+    /// assert_eq!("Hello".truncate_ellipsis(5), "Hello");
+    /// ```
+    #[unstable(feature = "str_truncate_ellipsis",
+               reason = "counting chars rather than display width is undecided",
+               issue = "0")]
+    pub fn truncate_ellipsis(&self, max_chars: usize) -> String {
+        if max_chars == 0 {
+            return String::new();
+        }
+        let mut starts = self.char_indices().map(|(i, _)| i).skip(max_chars - 1);
+        match (starts.next(), starts.next()) {
+            // There is at least one more character after the last one that
+            // would fit, so make room for the ellipsis.
+            (Some(end), Some(_)) => {
+                let mut result = String::with_capacity(end + '…'.len_utf8());
+                result.push_str(unsafe { self.slice_unchecked(0, end) });
+                result.push('…');
+                result
+            }
+            _ => String::from(self),
+        }
+    }
+
     /// Returns true if this `str` is entirely whitespace, and false otherwise.
     ///
     /// 'Whitespace' is defined according to the terms of the Unicode Derived Core
@@ -2244,6 +2362,43 @@ impl str {
         unsafe { String::from_utf8_unchecked(bytes) }
     }
 
+    /// Returns a copy of this string where the first character of each
+    /// word is mapped to its ASCII upper case equivalent and the remaining
+    /// characters to their ASCII lower case equivalent.
+    ///
+    /// Words are separated by ASCII whitespace. Non-ASCII characters are
+    /// unchanged, so a word starting with one keeps it as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_ascii_titlecase)]
+    ///
+    /// assert_eq!("the quick BROWN fox".ascii_titlecase(), "The Quick Brown Fox");
+    /// assert_eq!("3RD élan".ascii_titlecase(), "3rd élan");
+    /// ```
+    #[unstable(feature = "str_ascii_titlecase",
+               reason = "word boundary rules are not yet settled",
+               issue = "0")]
+    pub fn ascii_titlecase(&self) -> String {
+        let mut bytes = self.as_bytes().to_vec();
+        let mut word_start = true;
+        for byte in &mut bytes {
+            if byte.is_ascii_whitespace() {
+                word_start = true;
+            } else {
+                if word_start {
+                    byte.make_ascii_uppercase();
+                } else {
+                    byte.make_ascii_lowercase();
+                }
+                word_start = false;
+            }
+        }
+        // Only ASCII bytes were changed, which preserves the UTF-8 invariant.
+        unsafe { String::from_utf8_unchecked(bytes) }
+    }
+
     /// Checks that two strings are an ASCII case-insensitive match.
     ///
     /// Same as `to_ascii_lowercase(a) == to_ascii_lowercase(b)`,
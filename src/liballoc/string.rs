@@ -1019,8 +1019,8 @@ impl String {
     /// The capacity will remain at least as large as both the length
     /// and the supplied value.
     ///
-    /// Panics if the current capacity is smaller than the supplied
-    /// minimum capacity.
+    /// If the current capacity is less than or equal to the lower limit, this
+    /// is a no-op.
     ///
     /// # Examples
     ///
@@ -1215,32 +1215,46 @@ impl String {
     pub fn retain<F>(&mut self, mut f: F)
         where F: FnMut(char) -> bool
     {
+        // The bytes between `idx - del_bytes` and `idx` may hold a partial
+        // copy of a character, so the length is cut back to the compacted
+        // prefix when the guard is dropped. This keeps the string valid
+        // UTF-8 even if `f` panics.
+        struct SetLenOnDrop<'a> {
+            s: &'a mut String,
+            idx: usize,
+            del_bytes: usize,
+        }
+
+        impl<'a> Drop for SetLenOnDrop<'a> {
+            fn drop(&mut self) {
+                let new_len = self.idx - self.del_bytes;
+                debug_assert!(new_len <= self.s.len());
+                unsafe { self.s.vec.set_len(new_len); }
+            }
+        }
+
         let len = self.len();
-        let mut del_bytes = 0;
-        let mut idx = 0;
+        let mut guard = SetLenOnDrop { s: self, idx: 0, del_bytes: 0 };
 
-        while idx < len {
+        while guard.idx < len {
             let ch = unsafe {
-                self.slice_unchecked(idx, len).chars().next().unwrap()
+                guard.s.slice_unchecked(guard.idx, len).chars().next().unwrap()
             };
             let ch_len = ch.len_utf8();
 
             if !f(ch) {
-                del_bytes += ch_len;
-            } else if del_bytes > 0 {
+                guard.del_bytes += ch_len;
+            } else if guard.del_bytes > 0 {
                 unsafe {
-                    ptr::copy(self.vec.as_ptr().offset(idx as isize),
-                              self.vec.as_mut_ptr().offset((idx - del_bytes) as isize),
+                    ptr::copy(guard.s.vec.as_ptr().offset(guard.idx as isize),
+                              guard.s.vec.as_mut_ptr()
+                                  .offset((guard.idx - guard.del_bytes) as isize),
                               ch_len);
                 }
             }
 
             // Point idx to the next char
-            idx += ch_len;
-        }
-
-        if del_bytes > 0 {
-            unsafe { self.vec.set_len(len - del_bytes); }
+            guard.idx += ch_len;
         }
     }
 
@@ -1517,8 +1531,8 @@ impl String {
         }
     }
 
-    /// Creates a splicing iterator that removes the specified range in the string,
-    /// and replaces it with the given string.
+    /// Removes the specified range in the string, and replaces it with the
+    /// given string.
     /// The given string doesn't need to be the same length as the range.
     ///
     /// Note: Unlike [`Vec::splice`], the replacement happens eagerly, and this
@@ -1542,17 +1556,17 @@ impl String {
     /// let beta_offset = s.find('β').unwrap_or(s.len());
     ///
     /// // Replace the range up until the β from the string
-    /// s.splice(..beta_offset, "Α is capital alpha; ");
+    /// s.replace_range(..beta_offset, "Α is capital alpha; ");
     /// assert_eq!(s, "Α is capital alpha; β is beta");
     /// ```
     #[unstable(feature = "splice", reason = "recently added", issue = "44643")]
-    pub fn splice<R>(&mut self, range: R, replace_with: &str)
+    pub fn replace_range<R>(&mut self, range: R, replace_with: &str)
         where R: RangeBounds<usize>
     {
         // Memory safety
         //
-        // The String version of Splice does not have the memory safety issues
-        // of the vector version. The data is just plain bytes.
+        // Unlike `Vec::splice`, this has no memory safety issues, since the
+        // data is just plain bytes.
 
         match range.start() {
              Included(&n) => assert!(self.is_char_boundary(n)),
@@ -1570,6 +1584,20 @@ impl String {
         }.splice(range, replace_with.bytes());
     }
 
+    /// Removes the specified range in the string, and replaces it with the
+    /// given string.
+    ///
+    /// This is the old name of [`replace_range`], kept for one release.
+    ///
+    /// [`replace_range`]: #method.replace_range
+    #[unstable(feature = "splice", reason = "recently added", issue = "44643")]
+    #[rustc_deprecated(since = "1.27.0", reason = "renamed to replace_range")]
+    pub fn splice<R>(&mut self, range: R, replace_with: &str)
+        where R: RangeBounds<usize>
+    {
+        self.replace_range(range, replace_with)
+    }
+
     /// Converts this `String` into a [`Box`]`<`[`str`]`>`.
     ///
     /// This will drop any excess capacity.
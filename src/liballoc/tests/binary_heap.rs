@@ -65,6 +65,19 @@ fn test_move_iter_size_hint() {
     assert_eq!(it.next(), None);
 }
 
+#[test]
+fn test_into_iter_sorted() {
+    let data = vec![5, 9, 3, 10, 1, 7, 2, 8, 6, 4];
+    let heap = BinaryHeap::from(data);
+
+    let mut it = heap.into_iter_sorted();
+    assert_eq!(it.size_hint(), (10, Some(10)));
+    let top: Vec<_> = it.by_ref().take(3).collect();
+    assert_eq!(top, [10, 9, 8]);
+    assert_eq!(it.len(), 7);
+    assert_eq!(it.collect::<Vec<_>>(), [7, 6, 5, 4, 3, 2, 1]);
+}
+
 #[test]
 fn test_move_iter_reverse() {
     let data = vec![5, 9, 3];
@@ -212,6 +225,36 @@ fn test_from_iter() {
     }
 }
 
+#[test]
+fn test_remove_if() {
+    let data = vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+    let mut heap = BinaryHeap::from(data);
+
+    // remove elements from the middle of the storage, not just the top
+    assert_eq!(heap.remove_if(|&x| x == 7), Some(7));
+    assert_eq!(heap.remove_if(|&x| x == 2), Some(2));
+    assert_eq!(heap.remove_if(|&x| x > 100), None);
+    assert_eq!(heap.len(), 9);
+    assert!(!heap.contains(&7));
+
+    let mut drained = Vec::new();
+    while let Some(x) = heap.pop() {
+        drained.push(x);
+    }
+    assert_eq!(drained, [10, 9, 8, 6, 5, 4, 3, 1, 0]);
+
+    let mut rng = thread_rng();
+    for _ in 0..20 {
+        let mut heap: BinaryHeap<u32> = (0..50).map(|_| rng.gen_range(0, 30)).collect();
+        while let Some(x) = heap.remove_if(|&x| x % 3 == 0) {
+            assert_eq!(x % 3, 0);
+        }
+        let v = heap.into_sorted_vec();
+        assert!(v.iter().all(|&x| x % 3 != 0));
+        assert!(v.windows(2).all(|w| w[0] <= w[1]));
+    }
+}
+
 #[test]
 fn test_drain() {
     let mut q: BinaryHeap<_> = [9, 8, 7, 6, 5, 4, 3, 2, 1].iter().cloned().collect();
@@ -221,6 +264,73 @@ fn test_drain() {
     assert!(q.is_empty());
 }
 
+#[test]
+fn test_drain_keeps_capacity() {
+    let data = vec![5, 9, 3, 7, 1, 8];
+    let mut q: BinaryHeap<_> = data.iter().cloned().collect();
+    let cap = q.capacity();
+
+    let mut drained: Vec<_> = q.drain().collect();
+    drained.sort();
+    let mut expected = data.clone();
+    expected.sort();
+    assert_eq!(drained, expected);
+
+    assert!(q.is_empty());
+    assert_eq!(q.capacity(), cap);
+
+    q.extend(data.iter().cloned());
+    drop(q.drain());
+    assert!(q.is_empty());
+    assert_eq!(q.capacity(), cap);
+}
+
+#[test]
+fn test_reserve_capacity() {
+    let mut heap = BinaryHeap::from(vec![3, 1, 2]);
+    heap.reserve(100);
+    let cap = heap.capacity();
+    assert!(cap >= 103);
+    for i in 0..100 {
+        heap.push(i);
+    }
+    assert_eq!(heap.capacity(), cap);
+
+    let mut heap = BinaryHeap::with_capacity(4);
+    heap.push(1);
+    heap.reserve_exact(10);
+    assert!(heap.capacity() >= 11);
+    heap.shrink_to_fit();
+    assert_eq!(heap.capacity(), 1);
+}
+
+#[test]
+fn test_contains() {
+    let heap = BinaryHeap::from(vec![9, 1, 5, 3, 7]);
+    assert!(heap.contains(&9));
+    assert!(heap.contains(&1));
+    assert!(!heap.contains(&4));
+    assert_eq!(heap.peek(), Some(&9));
+    assert_eq!(heap.into_sorted_vec(), vec![1, 3, 5, 7, 9]);
+
+    let empty: BinaryHeap<i32> = BinaryHeap::new();
+    assert!(!empty.contains(&0));
+}
+
+#[test]
+fn test_rebuild() {
+    let mut heap = BinaryHeap::from(vec![2, 4, 6, 8, 10, 12]);
+    for x in heap.as_mut_slice() {
+        *x = 13 - *x;
+        if *x == 11 {
+            *x = 20;
+        }
+    }
+    heap.rebuild();
+    assert_eq!(heap.peek(), Some(&20));
+    assert_eq!(heap.into_sorted_vec(), vec![1, 3, 5, 7, 9, 20]);
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = BinaryHeap::new();
@@ -121,6 +121,90 @@ fn test_iter_rev() {
     test(size, map.into_iter().rev());
 }
 
+#[test]
+fn test_get_mut() {
+    let mut map: BTreeMap<_, _> = (0..100).map(|i| (i, i)).collect();
+
+    *map.get_mut(&37).unwrap() += 1000;
+    assert_eq!(map.get(&37), Some(&1037));
+    assert_eq!(map.get_mut(&100), None);
+
+    // borrowed key lookup
+    let mut map = BTreeMap::new();
+    map.insert(String::from("a"), 1);
+    *map.get_mut("a").unwrap() = 2;
+    assert_eq!(map["a"], 2);
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_first_last_key_value() {
+    let mut map = BTreeMap::new();
+    assert_eq!(map.first_key_value(), None);
+    assert_eq!(map.last_key_value(), None);
+    assert!(map.first_entry().is_none());
+    assert!(map.last_entry().is_none());
+
+    map.insert(1, 1);
+    assert_eq!(map.first_key_value(), Some((&1, &1)));
+    assert_eq!(map.last_key_value(), Some((&1, &1)));
+
+    // large enough to need internal nodes
+    let size = 1000;
+    let mut map: BTreeMap<_, _> = (0..size).rev().map(|i| (i, i * 10)).collect();
+    assert_eq!(map.first_key_value(), Some((&0, &0)));
+    assert_eq!(map.last_key_value(), Some((&(size - 1), &((size - 1) * 10))));
+
+    *map.first_entry().unwrap().get_mut() = 42;
+    assert_eq!(map[&0], 42);
+    assert_eq!(map.last_entry().unwrap().remove(), (size - 1) * 10);
+    assert_eq!(map.len(), size - 1);
+    assert_eq!(map.last_key_value(), Some((&(size - 2), &((size - 2) * 10))));
+}
+
+#[test]
+fn test_pop_first_last() {
+    let mut map = BTreeMap::new();
+    assert_eq!(map.pop_first(), None);
+    assert_eq!(map.pop_last(), None);
+
+    let mut rng = DeterministicRng::new();
+    for _ in 0..1000 {
+        let k = rng.next();
+        map.insert(k, k.wrapping_mul(3));
+    }
+    let expected: Vec<_> = map.iter().map(|(&k, &v)| (k, v)).collect();
+
+    let mut popped = Vec::new();
+    while let Some(kv) = map.pop_first() {
+        popped.push(kv);
+    }
+    assert_eq!(popped, expected);
+    assert!(map.is_empty());
+
+    let mut map: BTreeMap<_, _> = (0..1000).map(|i| (i, i)).collect();
+    for i in (0..1000).rev() {
+        assert_eq!(map.pop_last(), Some((i, i)));
+        assert_eq!(map.len(), i);
+    }
+    assert_eq!(map.pop_last(), None);
+}
+
+#[test]
+fn test_keys_values() {
+    let map: BTreeMap<_, _> = vec![(3, 'c'), (1, 'a'), (4, 'd'), (2, 'b')].into_iter().collect();
+
+    let keys: Vec<_> = map.keys().cloned().collect();
+    assert_eq!(keys, [1, 2, 3, 4]);
+    let values: Vec<_> = map.values().cloned().collect();
+    assert_eq!(values, ['a', 'b', 'c', 'd']);
+
+    assert!(map.keys().rev().eq([4, 3, 2, 1].iter()));
+    assert!(map.values().rev().eq(['d', 'c', 'b', 'a'].iter()));
+    assert_eq!(map.keys().len(), 4);
+    assert_eq!(map.values().len(), 4);
+}
+
 #[test]
 fn test_values_mut() {
     let mut a = BTreeMap::new();
@@ -135,6 +219,16 @@ fn test_values_mut() {
     assert_eq!(values, [String::from("hello!"), String::from("goodbye!")]);
 }
 
+#[test]
+fn test_values_mut_increment() {
+    let mut map: BTreeMap<_, _> = (0..100).rev().map(|i| (i, i * 2)).collect();
+    for value in map.values_mut() {
+        *value += 1;
+    }
+    assert_eq!(map.len(), 100);
+    assert!(map.into_iter().eq((0..100).map(|i| (i, i * 2 + 1))));
+}
+
 #[test]
 fn test_iter_mixed() {
     let size = 10000;
@@ -162,6 +256,46 @@ fn test_iter_mixed() {
     test(size, map.into_iter());
 }
 
+#[test]
+fn test_iter_meet_in_middle() {
+    let map: BTreeMap<_, _> = (1..6).map(|i| (i, i * 10)).collect();
+
+    let mut iter = map.iter();
+    assert_eq!(iter.next(), Some((&1, &10)));
+    assert_eq!(iter.next(), Some((&2, &20)));
+    assert_eq!(iter.next_back(), Some((&5, &50)));
+    assert_eq!(iter.next_back(), Some((&4, &40)));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some((&3, &30)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let keys: Vec<_> = map.iter().rev().map(|(&k, _)| k).collect();
+    assert_eq!(keys, [5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn test_into_iter_sorted() {
+    let value = Rc::new(());
+    let mut map = BTreeMap::new();
+    for &k in &[5, 1, 4, 2, 3] {
+        map.insert(k, value.clone());
+    }
+    assert_eq!(Rc::strong_count(&value), 6);
+
+    let keys: Vec<_> = map.into_iter().map(|(k, _)| k).collect();
+    assert_eq!(keys, [1, 2, 3, 4, 5]);
+    // every value has been moved out of the map and dropped
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let map: BTreeMap<_, _> = (0..5).map(|i| (i, value.clone())).collect();
+    let mut iter = map.into_iter();
+    assert_eq!(iter.next_back().map(|(k, _)| k), Some(4));
+    assert_eq!(iter.next().map(|(k, _)| k), Some(0));
+    drop(iter);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
 #[test]
 fn test_range_small() {
     let size = 5;
@@ -332,6 +466,24 @@ fn test_range_mut() {
     }
 }
 
+#[test]
+fn test_range_mut_update() {
+    let size = 200;
+    let mut map: BTreeMap<_, _> = (0..size).map(|i| (i, i)).collect();
+
+    for (_, v) in map.range_mut(50..150) {
+        *v *= 2;
+    }
+
+    for (&k, &v) in &map {
+        if 50 <= k && k < 150 {
+            assert_eq!(v, k * 2);
+        } else {
+            assert_eq!(v, k);
+        }
+    }
+}
+
 #[test]
 fn test_borrow() {
     // make sure these compile -- using the Borrow trait
@@ -411,6 +563,19 @@ fn test_entry() {
     assert_eq!(map.len(), 6);
 }
 
+#[test]
+fn test_entry_or_insert_with_caches() {
+    let mut map = BTreeMap::new();
+    let mut calls = 0;
+    for _ in 0..2 {
+        let v = map.entry("key").or_insert_with(|| { calls += 1; 10 });
+        *v += 1;
+    }
+    assert_eq!(calls, 1);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map["key"], 12);
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = BTreeMap::new();
@@ -643,6 +808,80 @@ create_append_test!(test_append_181, 181);
 create_append_test!(test_append_239, 239);
 create_append_test!(test_append_1700, 1700);
 
+#[test]
+fn test_retain() {
+    let mut map: BTreeMap<i32, i32> = (0..1000).map(|x| (x, x * 10)).collect();
+    map.retain(|&k, v| {
+        *v += 1;
+        k % 2 == 0
+    });
+    assert_eq!(map.len(), 500);
+    assert!(map.iter().map(|(&k, &v)| (k, v)).eq((0..1000).step_by(2).map(|x| (x, x * 10 + 1))));
+
+    // the pruned tree must still support mutation
+    assert_eq!(map.remove(&998), Some(9981));
+    assert_eq!(map.insert(1, 0), None);
+    assert_eq!(map.len(), 500);
+
+    map.retain(|_, _| false);
+    assert!(map.is_empty());
+    assert_eq!(map.insert(3, 3), None);
+}
+
+#[test]
+fn test_retain_random() {
+    // remove pairs all over a multi-level tree, including runs that empty
+    // whole leaves and force merges up to the root
+    let data = rand_data(2000);
+    for &modulus in &[2, 3, 7, 100] {
+        let mut map = BTreeMap::from_iter(data.clone());
+        let len = map.len();
+        let mut expected: Vec<_> = map.iter().map(|(&k, &v)| (k, v)).collect();
+        expected.retain(|&(k, _)| k % modulus == 0 || (k / 1000) % 4 == 0);
+
+        let mut visited = Vec::new();
+        map.retain(|&k, _| {
+            visited.push(k);
+            k % modulus == 0 || (k / 1000) % 4 == 0
+        });
+        assert!(visited.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(visited.len(), len);
+        assert_eq!(map.len(), expected.len());
+        assert!(map.iter().map(|(&k, &v)| (k, v)).eq(expected.iter().cloned()));
+
+        for &(k, v) in &expected {
+            assert_eq!(map.remove(&k), Some(v));
+        }
+        assert!(map.is_empty());
+    }
+}
+
+#[test]
+fn test_retain_panic() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let mut map: BTreeMap<i32, i32> = (0..100).map(|x| (x, x)).collect();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        map.retain(|&k, _| {
+            if k == 50 {
+                panic!("panic in predicate");
+            }
+            k % 2 == 0
+        });
+    }));
+    assert!(result.is_err());
+
+    // everything before the panic was pruned, everything after it is intact
+    let expected: Vec<_> = (0..50).filter(|k| k % 2 == 0).chain(50..100).collect();
+    assert_eq!(map.len(), expected.len());
+    assert!(map.keys().cloned().eq(expected.iter().cloned()));
+
+    assert_eq!(map.insert(1, 1), None);
+    assert_eq!(map.remove(&99), Some(99));
+    map.retain(|&k, _| k < 10);
+    assert!(map.keys().cloned().eq(vec![0, 1, 2, 4, 6, 8]));
+}
+
 fn rand_data(len: usize) -> Vec<(u32, u32)> {
     let mut rng = DeterministicRng::new();
     Vec::from_iter((0..len).map(|_| (rng.next(), rng.next())))
@@ -108,6 +108,20 @@ fn test_symmetric_difference() {
                                &[-2, 1, 5, 11, 14, 22]);
 }
 
+#[test]
+fn test_symmetric_difference_manual() {
+    let a: BTreeSet<i32> = (0..60).filter(|x| x % 2 == 0).collect();
+    let b: BTreeSet<i32> = (0..60).filter(|x| x % 3 == 0).collect();
+    let expected: Vec<i32> = (0..60).filter(|x| (x % 2 == 0) != (x % 3 == 0)).collect();
+
+    assert_eq!(a.symmetric_difference(&b).cloned().collect::<Vec<_>>(), expected);
+    assert_eq!(b.symmetric_difference(&a).cloned().collect::<Vec<_>>(), expected);
+
+    let empty = BTreeSet::new();
+    assert!(a.symmetric_difference(&empty).eq(a.iter()));
+    assert!(empty.symmetric_difference(&b).eq(b.iter()));
+}
+
 #[test]
 fn test_union() {
     fn check_union(a: &[i32], b: &[i32], expected: &[i32]) {
@@ -122,6 +136,107 @@ fn test_union() {
                 &[-2, 1, 3, 5, 9, 11, 13, 16, 19, 24]);
 }
 
+#[test]
+fn test_is_subset() {
+    fn is_subset(a: &[i32], b: &[i32]) -> bool {
+        let set_a = a.iter().collect::<BTreeSet<_>>();
+        let set_b = b.iter().collect::<BTreeSet<_>>();
+        assert_eq!(set_a.is_subset(&set_b), set_b.is_superset(&set_a));
+        set_a.is_subset(&set_b)
+    }
+
+    assert_eq!(is_subset(&[], &[]), true);
+    assert_eq!(is_subset(&[], &[1, 2]), true);
+    assert_eq!(is_subset(&[1, 2], &[]), false);
+    assert_eq!(is_subset(&[1, 2], &[1, 2]), true);
+    assert_eq!(is_subset(&[2], &[1, 2, 3]), true);
+    assert_eq!(is_subset(&[1, 2, 3], &[2]), false);
+    assert_eq!(is_subset(&[1, 4], &[1, 2, 3]), false);
+    assert_eq!(is_subset(&[0, 3], &[1, 2, 3]), false);
+    assert_eq!(is_subset(&[1, 3, 5], &[-1, 1, 2, 3, 4, 5]), true);
+}
+
+#[test]
+fn test_is_disjoint() {
+    fn is_disjoint(a: &[i32], b: &[i32]) -> bool {
+        let set_a = a.iter().collect::<BTreeSet<_>>();
+        let set_b = b.iter().collect::<BTreeSet<_>>();
+        assert_eq!(set_a.is_disjoint(&set_b), set_b.is_disjoint(&set_a));
+        set_a.is_disjoint(&set_b)
+    }
+
+    assert_eq!(is_disjoint(&[], &[]), true);
+    assert_eq!(is_disjoint(&[], &[1, 2]), true);
+    assert_eq!(is_disjoint(&[1, 2], &[1, 2]), false);
+    assert_eq!(is_disjoint(&[1, 3, 5], &[2, 4, 6]), true);
+    assert_eq!(is_disjoint(&[1, 3, 5], &[2, 3, 4]), false);
+    assert_eq!(is_disjoint(&[1, 2], &[3, 4]), true);
+}
+
+#[test]
+fn test_range() {
+    let set: BTreeSet<usize> = [1, 100, 1000, 100000].iter().cloned().collect();
+
+    assert_eq!(set.range(100..).cloned().collect::<Vec<_>>(), [100, 1000, 100000]);
+    assert_eq!(set.range(101..).cloned().collect::<Vec<_>>(), [1000, 100000]);
+    assert_eq!(set.range(2..100000).cloned().collect::<Vec<_>>(), [100, 1000]);
+    assert_eq!(set.range(50..5000).rev().cloned().collect::<Vec<_>>(), [1000, 100]);
+    assert_eq!(set.range(2..100).count(), 0);
+}
+
+#[test]
+fn test_first_last() {
+    let mut a = BTreeSet::new();
+    assert_eq!(a.first(), None);
+    assert_eq!(a.last(), None);
+    assert_eq!(a.pop_first(), None);
+    assert_eq!(a.pop_last(), None);
+
+    a.insert(1);
+    assert_eq!(a.first(), Some(&1));
+    assert_eq!(a.last(), Some(&1));
+
+    // large enough to need internal nodes, so popping rebalances
+    a.extend(2..1000);
+    assert_eq!(a.first(), Some(&1));
+    assert_eq!(a.last(), Some(&999));
+    for i in 1..500 {
+        assert_eq!(a.pop_first(), Some(i));
+        assert_eq!(a.pop_last(), Some(1000 - i));
+    }
+    assert_eq!(a.len(), 1);
+    assert_eq!(a.pop_first(), Some(500));
+    assert!(a.is_empty());
+}
+
+#[test]
+fn test_pop_first_sorted() {
+    let mut rng = DeterministicRng::new();
+    let mut set = BTreeSet::new();
+    for _ in 0..1000 {
+        set.insert(rng.next());
+    }
+    let expected: Vec<_> = set.iter().cloned().collect();
+
+    let mut drained = Vec::new();
+    while let Some(x) = set.pop_first() {
+        drained.push(x);
+    }
+    assert_eq!(drained, expected);
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_pop_last_sorted() {
+    let mut set: BTreeSet<_> = (0..100).map(|x| x * 7 % 100).collect();
+    let mut drained = Vec::new();
+    while let Some(x) = set.pop_last() {
+        drained.push(x);
+    }
+    assert!(drained.into_iter().eq((0..100).rev()));
+    assert!(set.is_empty());
+}
+
 #[test]
 fn test_zip() {
     let mut x = BTreeSet::new();
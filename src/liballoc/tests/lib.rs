@@ -13,21 +13,46 @@
 #![feature(allocator_api)]
 #![feature(alloc_system)]
 #![feature(attr_literals)]
+#![feature(binary_heap_contains)]
+#![feature(binary_heap_into_iter_sorted)]
+#![feature(binary_heap_rebuild)]
+#![feature(binary_heap_remove_if)]
 #![feature(box_syntax)]
 #![cfg_attr(stage0, feature(inclusive_range_syntax))]
+#![feature(btree_retain)]
 #![feature(const_fn)]
 #![feature(drain_filter)]
 #![feature(exact_size_is_empty)]
+#![feature(extend_front)]
 #![feature(iterator_step_by)]
+#![feature(linked_list_to_vec_rev)]
+#![feature(map_first_last)]
 #![feature(pattern)]
 #![feature(rand)]
+#![feature(shrink_to)]
 #![feature(slice_sort_by_cached_key)]
 #![feature(splice)]
+#![feature(str_ascii_titlecase)]
+#![feature(str_byte_lines)]
+#![feature(str_count_matches)]
 #![feature(str_escape)]
+#![feature(str_strip)]
+#![feature(str_truncate_ellipsis)]
 #![feature(string_retain)]
 #![feature(try_reserve)]
 #![feature(unboxed_closures)]
 #![feature(unicode)]
+#![feature(vec_dedup_count)]
+#![feature(vec_drain_tail)]
+#![feature(vec_from_elem)]
+#![feature(vec_from_fn)]
+#![feature(vec_insert_many)]
+#![feature(vec_partition_in_place)]
+#![feature(vec_remove_item)]
+#![feature(vec_sort_dedup)]
+#![feature(vec_take)]
+#![feature(vecdeque_binary_search)]
+#![feature(vecdeque_rotate)]
 #![feature(exact_chunks)]
 #![feature(inclusive_range_fields)]
 
@@ -354,6 +354,29 @@ fn test_extend() {
     assert!(a.iter().eq(&[1, 2, 3, 4, 5, 6, 7]));
 }
 
+#[test]
+fn test_extend_front() {
+    let mut a = list_from(&[4, 5]);
+    a.extend_front(vec![1, 2, 3]);
+    assert_eq!(a.len(), 5);
+    assert!(a.iter().eq(&[1, 2, 3, 4, 5]));
+
+    let mut b = LinkedList::new();
+    b.extend_front(vec![1, 2]);
+    assert!(b.iter().eq(&[1, 2]));
+    b.extend_front(Vec::new());
+    assert!(b.iter().eq(&[1, 2]));
+}
+
+#[test]
+fn test_to_vec_rev() {
+    let list = list_from(&[1, 2, 3]);
+    assert_eq!(list.to_vec_rev(), [3, 2, 1]);
+
+    let empty: LinkedList<i32> = LinkedList::new();
+    assert!(empty.to_vec_rev().is_empty());
+}
+
 #[test]
 fn test_contains() {
     let mut l = LinkedList::new();
@@ -367,6 +390,19 @@ fn test_contains() {
     assert!(!l.contains(&3));
 }
 
+#[test]
+fn test_position_rposition() {
+    let list = list_from(&[5, 7, 9, 7, 5]);
+
+    // `rposition` counts from the front too, but scans from the back
+    assert_eq!(list.iter().position(|&x| x == 7), Some(1));
+    assert_eq!(list.iter().rposition(|&x| x == 7), Some(3));
+    assert_eq!(list.iter().position(|&x| x == 5), Some(0));
+    assert_eq!(list.iter().rposition(|&x| x == 5), Some(4));
+    assert_eq!(list.iter().position(|&x| x == 1), None);
+    assert_eq!(list.iter().rposition(|&x| x == 1), None);
+}
+
 #[test]
 fn drain_filter_empty() {
     let mut list: LinkedList<i32> = LinkedList::new();
@@ -171,6 +171,30 @@ fn test_split_last_mut() {
     assert!(a.split_last_mut() == Some((&mut 12, b)));
 }
 
+#[test]
+fn test_split_first_last_recursive() {
+    fn sum(v: &[i32]) -> i32 {
+        match v.split_first() {
+            Some((&x, rest)) => x + sum(rest),
+            None => 0,
+        }
+    }
+    assert_eq!(sum(&[1, 2, 3, 4]), 10);
+
+    // write through both halves of a mutable split
+    let mut v = [1, 2, 3];
+    {
+        let (first, rest) = v.split_first_mut().unwrap();
+        *first = 10;
+        rest[0] = 20;
+    }
+    {
+        let (last, init) = v.split_last_mut().unwrap();
+        *last += init.iter().sum::<i32>();
+    }
+    assert_eq!(v, [10, 20, 33]);
+}
+
 #[test]
 fn test_last() {
     let mut a = vec![];
@@ -607,6 +631,15 @@ fn test_join() {
     assert_eq!(v.join(&0), [1, 0, 2, 3]);
     let v: [&[_]; 3] = [&[1], &[2], &[3]];
     assert_eq!(v.join(&0), [1, 0, 2, 0, 3]);
+
+    let v: &[&[_]] = &[&[1, 2], &[3], &[4, 5]];
+    let joined = v.join(&0);
+    assert_eq!(joined, [1, 2, 0, 3, 0, 4, 5]);
+    assert_eq!(joined.capacity(), joined.len());
+    let v: &[&[_]] = &[&[1, 2]];
+    assert_eq!(v.join(&0), [1, 2]);
+    let v: &[&[i32]] = &[];
+    assert!(v.join(&0).is_empty());
 }
 
 #[test]
@@ -1173,6 +1206,29 @@ fn test_mut_splitator() {
     assert!(xs == [0, 1, 0, 3, 2, 0, 0, 5, 4, 0, 6, 7]);
 }
 
+#[test]
+fn test_mut_splitator_segments() {
+    let mut xs = [1, 0, 2, 3, 0, 4];
+    {
+        let mut segments = xs.split_mut(|x| *x == 0);
+        let a = segments.next().unwrap();
+        let b = segments.next().unwrap();
+        let c = segments.next().unwrap();
+        assert!(segments.next().is_none());
+        assert_eq!((&*a, &*b, &*c), (&[1][..], &[2, 3][..], &[4][..]));
+
+        // all segments are alive at once since they don't overlap
+        a[0] += 10;
+        b[1] += c[0];
+        c[0] = 0;
+    }
+    assert_eq!(xs, [11, 0, 2, 7, 0, 0]);
+
+    let mut xs = [0, 0];
+    let lens: Vec<usize> = xs.split_mut(|x| *x == 0).map(|s| s.len()).collect();
+    assert_eq!(lens, [0, 0, 0]);
+}
+
 #[test]
 fn test_mut_splitator_rev() {
     let mut xs = [1, 2, 0, 3, 4, 0, 0, 5, 6, 0];
@@ -1361,6 +1417,31 @@ fn test_copy_from_slice_dst_shorter() {
     dst.copy_from_slice(&src);
 }
 
+#[test]
+fn test_copy_from_slice_into_subslice() {
+    let src = [7, 8, 9];
+    let mut dst = [0; 6];
+    dst[2..5].copy_from_slice(&src);
+    assert_eq!(dst, [0, 0, 7, 8, 9, 0]);
+}
+
+#[test]
+fn test_clone_from_slice() {
+    let src = [String::from("a"), String::from("b")];
+    let mut dst = [String::new(), String::new(), String::from("c")];
+    dst[..2].clone_from_slice(&src);
+    assert_eq!(dst, ["a", "b", "c"]);
+    assert_eq!(src, ["a", "b"]);
+}
+
+#[test]
+#[should_panic(expected = "destination and source slices have different lengths")]
+fn test_clone_from_slice_len_mismatch() {
+    let src = [String::from("a")];
+    let mut dst = [String::new(), String::new()];
+    dst.clone_from_slice(&src);
+}
+
 const MAX_LEN: usize = 80;
 
 static DROP_COUNTS: [AtomicUsize; MAX_LEN] = [
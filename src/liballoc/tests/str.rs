@@ -212,6 +212,30 @@ fn test_ends_with() {
     assert!("ddö".ends_with("dö"));
 }
 
+#[test]
+fn test_strip_prefix() {
+    assert_eq!("abc".strip_prefix(""), Some("abc"));
+    assert_eq!("".strip_prefix(""), Some(""));
+    assert_eq!("abc".strip_prefix("a"), Some("bc"));
+    assert_eq!("abc".strip_prefix("abc"), Some(""));
+    assert_eq!("abc".strip_prefix("b"), None);
+    assert_eq!("a".strip_prefix("abc"), None);
+    assert_eq!("ödd".strip_prefix("ö"), Some("dd"));
+    assert_eq!("ödd".strip_prefix('ö'), Some("dd"));
+}
+
+#[test]
+fn test_strip_suffix() {
+    assert_eq!("abc".strip_suffix(""), Some("abc"));
+    assert_eq!("".strip_suffix(""), Some(""));
+    assert_eq!("abc".strip_suffix("c"), Some("ab"));
+    assert_eq!("abc".strip_suffix("abc"), Some(""));
+    assert_eq!("abc".strip_suffix("b"), None);
+    assert_eq!("c".strip_suffix("abc"), None);
+    assert_eq!("ddö".strip_suffix("ö"), Some("dd"));
+    assert_eq!("ddö".strip_suffix('ö'), Some("dd"));
+}
+
 #[test]
 fn test_is_empty() {
     assert!("".is_empty());
@@ -223,6 +247,7 @@ fn test_replacen() {
     assert_eq!("".replacen('a', "b", 5), "");
     assert_eq!("acaaa".replacen("a", "b", 3), "bcbba");
     assert_eq!("aaaa".replacen("a", "b", 0), "aaaa");
+    assert_eq!("x.x.x.x".replacen("x", "yy", 2), "yy.yy.x.x");
 
     let test = "test";
     assert_eq!(" test test ".replacen(test, "toast", 3), " toast toast ");
@@ -506,6 +531,22 @@ fn test_trim_matches() {
     assert_eq!("123foo1bar123".trim_matches(|c: char| c.is_numeric()), "foo1bar");
 }
 
+#[test]
+fn test_trim_matches_predicate_multibyte() {
+    let digit = |c: char| c.is_ascii_digit();
+    assert_eq!("12abc34".trim_matches(digit), "abc");
+    assert_eq!("12abc34".trim_left_matches(digit), "abc34");
+    assert_eq!("12abc34".trim_right_matches(digit), "12abc");
+    assert_eq!("1234".trim_matches(digit), "");
+
+    // multibyte chars on both ends, matched and unmatched
+    let accent = |c: char| c == 'é' || c == '中';
+    assert_eq!("é中aé中bé".trim_matches(accent), "aé中b");
+    assert_eq!("é中aé中bé".trim_left_matches(accent), "aé中bé");
+    assert_eq!("é中aé中bé".trim_right_matches(accent), "é中aé中b");
+    assert_eq!("😀x😀".trim_matches(|c: char| c.is_ascii()), "😀x😀");
+}
+
 #[test]
 fn test_trim_left() {
     assert_eq!("".trim_left(), "");
@@ -715,6 +756,15 @@ fn test_split_at_mut() {
     assert_eq!(s, "HELLO world");
 }
 
+#[test]
+fn test_ascii_titlecase() {
+    assert_eq!("the quick BROWN fox".ascii_titlecase(), "The Quick Brown Fox");
+    assert_eq!("  two\tSPACES\n".ascii_titlecase(), "  Two\tSpaces\n");
+    assert_eq!("1ST place".ascii_titlecase(), "1st Place");
+    assert_eq!("éCOLE ünd".ascii_titlecase(), "école ünd");
+    assert_eq!("".ascii_titlecase(), "");
+}
+
 #[test]
 #[should_panic]
 fn test_split_at_boundscheck() {
@@ -938,6 +988,49 @@ fn test_char_indices_revator() {
     assert_eq!(pos, p.len());
 }
 
+#[test]
+fn test_char_indices_rev_matches_forward() {
+    let s = "a\u{e9}b\u{4e2d}c\u{1f600}d";
+    let forward: Vec<_> = s.char_indices().collect();
+    let mut backward: Vec<_> = s.char_indices().rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+    assert_eq!(forward, [(0, 'a'), (1, '\u{e9}'), (3, 'b'), (4, '\u{4e2d}'),
+                         (7, 'c'), (8, '\u{1f600}'), (12, 'd')]);
+
+    // meeting in the middle
+    let mut it = s.char_indices();
+    assert_eq!(it.next_back(), Some((12, 'd')));
+    assert_eq!(it.next(), Some((0, 'a')));
+    assert_eq!(it.next_back(), Some((8, '\u{1f600}')));
+    assert_eq!(it.next_back(), Some((7, 'c')));
+    assert_eq!(it.next(), Some((1, '\u{e9}')));
+    assert_eq!(it.next(), Some((3, 'b')));
+    assert_eq!(it.next_back(), Some((4, '\u{4e2d}')));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
+#[test]
+fn test_char_at_byte_index() {
+    // decoding at a byte offset is spelled with slicing and `chars`
+    let s = "a\u{e9}b\u{4e2d}c\u{1f600}d";
+    for (i, c) in s.char_indices() {
+        assert_eq!(s[i..].chars().next(), Some(c));
+        let end = i + c.len_utf8();
+        assert_eq!(s[..end].chars().next_back(), Some(c));
+    }
+    assert_eq!(s[s.len()..].chars().next(), None);
+    assert_eq!(s[..0].chars().next_back(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_char_at_not_char_boundary() {
+    let s = "a\u{e9}b";
+    s[2..].chars().next();
+}
+
 #[test]
 fn test_char_indices_last() {
     let s = "ศไทย中华Việt Nam";
@@ -975,6 +1068,27 @@ fn test_split_char_iterator_no_trailing() {
     assert_eq!(split, ["", "Märy häd ä little lämb", "Little lämb"]);
 }
 
+#[test]
+fn test_split_terminator() {
+    let split: Vec<&str> = "a,b,c,".split_terminator(',').collect();
+    assert_eq!(split, ["a", "b", "c"]);
+
+    let split: Vec<&str> = "a,b".split_terminator(',').collect();
+    assert_eq!(split, ["a", "b"]);
+
+    let split: Vec<&str> = "a,,".split_terminator(',').collect();
+    assert_eq!(split, ["a", ""]);
+
+    let split: Vec<&str> = "".split_terminator(',').collect();
+    assert!(split.is_empty());
+
+    // a trailing terminator and its absence give the same fields
+    let with: Vec<&str> = "a.b.c.".split_terminator('.').collect();
+    let without: Vec<&str> = "a.b.c".split_terminator('.').collect();
+    assert_eq!(with, ["a", "b", "c"]);
+    assert_eq!(with, without);
+}
+
 #[test]
 fn test_rsplit() {
     let data = "\nMäry häd ä little lämb\nLittle lämb\n";
@@ -1001,6 +1115,29 @@ fn test_rsplitn() {
 
     let split: Vec<&str> = data.rsplitn(2, |c: char| c == 'ä').collect();
     assert_eq!(split, ["mb\n", "\nMäry häd ä little lämb\nLittle l"]);
+
+    let split: Vec<&str> = "a.b.c.d".rsplitn(2, '.').collect();
+    assert_eq!(split, ["d", "a.b.c"]);
+
+    let split: Vec<&str> = "key=value=withequals".rsplitn(2, '=').collect();
+    assert_eq!(split, ["withequals", "key=value"]);
+}
+
+#[test]
+fn test_byte_lines() {
+    use std::str::byte_lines;
+
+    let data = b"first\r\nsecond\nth\xFFrd\r\n\nlast";
+    let lines: Vec<&[u8]> = byte_lines(data).collect();
+    assert_eq!(lines, [&b"first"[..], &b"second"[..], &b"th\xFFrd"[..], &b""[..], &b"last"[..]]);
+
+    let lines: Vec<&[u8]> = byte_lines(b"a\nb\n").collect();
+    assert_eq!(lines, [&b"a"[..], &b"b"[..]]);
+
+    let lines: Vec<&[u8]> = byte_lines(b"\r\n").collect();
+    assert_eq!(lines, [&b""[..]]);
+
+    assert_eq!(byte_lines(b"").next(), None);
 }
 
 #[test]
@@ -1010,6 +1147,21 @@ fn test_split_whitespace() {
     assert_eq!(words, ["Märy", "häd", "ä", "little", "lämb", "Little", "lämb"])
 }
 
+#[test]
+fn test_split_whitespace_runs() {
+    let words: Vec<&str> = "  hello \t world\n".split_whitespace().collect();
+    assert_eq!(words, ["hello", "world"]);
+
+    // non-ASCII whitespace: no-break space, ideographic space, line separator
+    let words: Vec<&str> = "a\u{a0}\u{3000}b\u{2028}\u{2028}c".split_whitespace().collect();
+    assert_eq!(words, ["a", "b", "c"]);
+
+    assert_eq!("".split_whitespace().next(), None);
+    assert_eq!(" \t\n\u{3000} ".split_whitespace().next(), None);
+    let words: Vec<&str> = "  hello \t world\n".split_whitespace().rev().collect();
+    assert_eq!(words, ["world", "hello"]);
+}
+
 #[test]
 fn test_lines() {
     let data = "\nMäry häd ä little lämb\n\r\nLittle lämb\n";
@@ -1021,6 +1173,19 @@ fn test_lines() {
     assert_eq!(lines, ["", "Märy häd ä little lämb", "", "Little lämb"]);
 }
 
+#[test]
+#[allow(deprecated)]
+fn test_lines_mixed_endings() {
+    let lines: Vec<&str> = "a\r\nb\nc".lines().collect();
+    assert_eq!(lines, ["a", "b", "c"]);
+    let lines: Vec<&str> = "a\r\nb\nc\r\n".lines().collect();
+    assert_eq!(lines, ["a", "b", "c"]);
+
+    // lines_any is the deprecated spelling of the same thing
+    let lines: Vec<&str> = "a\r\nb\nc\n".lines_any().collect();
+    assert_eq!(lines, ["a", "b", "c"]);
+}
+
 #[test]
 fn test_splitator() {
     fn t(s: &str, sep: &str, u: &[&str]) {
@@ -1095,6 +1260,34 @@ fn test_empty_match_indices() {
     assert_eq!(vec, [(0, ""), (1, ""), (3, ""), (6, ""), (7, "")]);
 }
 
+#[test]
+fn test_match_indices_multibyte_char() {
+    let data = "éa中é\u{1F600}xé";
+    let offsets: Vec<usize> = data.match_indices('é').map(|(i, _)| i).collect();
+    assert_eq!(offsets, [0, 6, 13]);
+    for &i in &offsets {
+        assert!(data[i..].starts_with('é'));
+    }
+
+    let offsets: Vec<usize> = data.rmatch_indices('é').map(|(i, _)| i).collect();
+    assert_eq!(offsets, [13, 6, 0]);
+    assert_eq!(data.match_indices('\u{1F600}').collect::<Vec<_>>(), [(8, "\u{1F600}")]);
+    assert_eq!(data.match_indices('z').next(), None);
+}
+
+#[test]
+fn test_count_matches() {
+    assert_eq!("abcXXXabcYYYabc".count_matches("abc"), 3);
+    assert_eq!("abcabc".count_matches("abc"), 2);
+    assert_eq!("aaaa".count_matches("aa"), 2);
+    assert_eq!("aaa".count_matches("aa"), 1);
+    assert_eq!("äöäöä".count_matches("ä"), 3);
+    assert_eq!("abc".count_matches("d"), 0);
+    assert_eq!("abc".count_matches(""), 0);
+    assert_eq!("".count_matches(""), 0);
+    assert_eq!("".count_matches("a"), 0);
+}
+
 #[test]
 fn test_bool_from_str() {
     assert_eq!("true".parse().ok(), Some(true));
@@ -1326,6 +1519,27 @@ fn test_repeat() {
     assert_eq!("".repeat(3), "");
     assert_eq!("abc".repeat(0), "");
     assert_eq!("α".repeat(3), "ααα");
+    assert_eq!("ab".repeat(3), "ababab");
+    assert_eq!("".repeat(0), "");
+
+    let s = "xyz".repeat(4);
+    assert_eq!(s.len(), 12);
+    assert!(s.capacity() >= 12);
+}
+
+#[test]
+fn test_truncate_ellipsis() {
+    assert_eq!("the quick brown fox".truncate_ellipsis(10), "the quick…");
+    assert_eq!("abcde".truncate_ellipsis(5), "abcde");
+    assert_eq!("abcde".truncate_ellipsis(10), "abcde");
+    assert_eq!("abcdef".truncate_ellipsis(5), "abcd…");
+    assert_eq!("".truncate_ellipsis(3), "");
+    assert_eq!("abc".truncate_ellipsis(0), "");
+    assert_eq!("abc".truncate_ellipsis(1), "…");
+
+    assert_eq!("αβγδε".truncate_ellipsis(4), "αβγ…");
+    assert_eq!("αβγδε".truncate_ellipsis(5), "αβγδε");
+    assert_eq!("日本語テキスト".truncate_ellipsis(3), "日本…");
 }
 
 mod pattern {
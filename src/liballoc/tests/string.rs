@@ -11,6 +11,8 @@
 use std::borrow::Cow;
 use std::collections::CollectionAllocErr::*;
 use std::mem::size_of;
+use std::panic::{self, AssertUnwindSafe};
+use std::str;
 use std::{usize, isize};
 
 pub trait IntoCow<'a, B: ?Sized> where B: ToOwned {
@@ -99,6 +101,22 @@ fn test_from_utf8_lossy() {
     let xs = b"\xED\xA0\x80foo\xED\xBF\xBFbar";
     assert_eq!(String::from_utf8_lossy(xs),
                String::from("\u{FFFD}\u{FFFD}\u{FFFD}foo\u{FFFD}\u{FFFD}\u{FFFD}bar").into_cow());
+
+    // lone continuation byte
+    let xs = b"a\x80b";
+    assert_eq!(String::from_utf8_lossy(xs),
+               String::from("a\u{FFFD}b").into_cow());
+
+    // truncated multibyte sequence at the end
+    let xs = b"abc\xE4\xB8";
+    assert_eq!(String::from_utf8_lossy(xs),
+               String::from("abc\u{FFFD}").into_cow());
+
+    // valid input is borrowed rather than copied
+    match String::from_utf8_lossy("aä中".as_bytes()) {
+        Cow::Borrowed(s) => assert_eq!(s, "aä中"),
+        Cow::Owned(_) => panic!("valid UTF-8 should not allocate"),
+    }
 }
 
 #[test]
@@ -229,6 +247,11 @@ fn test_pop() {
     assert_eq!(data.pop().unwrap(), 'b'); // 1 bytes
     assert_eq!(data.pop().unwrap(), '华');
     assert_eq!(data, "ประเทศไทย中");
+
+    let mut data = String::from("é");
+    assert_eq!(data.pop(), Some('é'));
+    assert_eq!(data.pop(), None);
+    assert_eq!(data, "");
 }
 
 #[test]
@@ -288,6 +311,15 @@ fn test_str_truncate() {
     assert_eq!(p_, p);
 }
 
+#[test]
+fn test_str_truncate_multibyte() {
+    let mut s = String::from("日本語");
+    s.truncate(6);
+    assert_eq!(s, "日本");
+    s.truncate(3);
+    assert_eq!(s, "日");
+}
+
 #[test]
 fn test_str_truncate_invalid_len() {
     let mut s = String::from("12345");
@@ -355,6 +387,31 @@ fn test_retain() {
     assert_eq!(s, "");
 }
 
+#[test]
+fn test_retain_digits() {
+    let mut s = String::from("a1b2c3");
+    s.retain(|c| !c.is_digit(10));
+    assert_eq!(s, "abc");
+}
+
+#[test]
+fn test_retain_panic() {
+    let mut s = String::from("0è0");
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut count = 0;
+        s.retain(|_| {
+            count += 1;
+            match count {
+                1 => false,
+                2 => true,
+                _ => panic!(),
+            }
+        });
+    }));
+    assert!(str::from_utf8(s.as_bytes()).is_ok());
+    assert_eq!(s, "è");
+}
+
 #[test]
 fn insert() {
     let mut s = "foobar".to_string();
@@ -375,6 +432,33 @@ fn insert_bad2() {
     "ệ".to_string().insert(1, 't');
 }
 
+#[test]
+fn insert_str() {
+    let mut s = "bar".to_string();
+    s.insert_str(0, "foo");
+    assert_eq!(s, "foobar");
+    s.insert_str(3, "ệย");
+    assert_eq!(s, "fooệยbar");
+    s.insert_str(6, "-");
+    assert_eq!(s, "fooệ-ยbar");
+    let len = s.len();
+    s.insert_str(len, "baz");
+    assert_eq!(s, "fooệ-ยbarbaz");
+    s.insert_str(3, "");
+    assert_eq!(s, "fooệ-ยbarbaz");
+}
+
+#[test]
+#[should_panic]
+fn insert_str_bad1() {
+    "".to_string().insert_str(1, "t");
+}
+#[test]
+#[should_panic]
+fn insert_str_bad2() {
+    "ệ".to_string().insert_str(1, "t");
+}
+
 #[test]
 fn test_slicing() {
     let s = "foobar".to_string();
@@ -443,53 +527,87 @@ fn test_drain() {
 }
 
 #[test]
-fn test_splice() {
+fn test_drain_partially_consumed() {
+    let mut s = String::from("añb中c€d");
+    {
+        let mut drain = s.drain(1..11);
+        assert_eq!(drain.next(), Some('ñ'));
+        assert_eq!(drain.next_back(), Some('€'));
+    }
+    assert_eq!(s, "ad");
+}
+
+#[test]
+#[should_panic]
+fn test_drain_not_char_boundary() {
+    let mut s = String::from("αβγ");
+    s.drain(1..4);
+}
+
+#[test]
+fn test_replace_range() {
     let mut s = "Hello, world!".to_owned();
-    s.splice(7..12, "世界");
+    s.replace_range(7..12, "世界");
     assert_eq!(s, "Hello, 世界!");
 }
 
+#[test]
+fn test_replace_range_lengths() {
+    let mut s = String::from("abc-def-ghi");
+    // longer replacement
+    s.replace_range(4..7, "uvwxyz");
+    assert_eq!(s, "abc-uvwxyz-ghi");
+    // shorter replacement
+    s.replace_range(4..10, "é");
+    assert_eq!(s, "abc-é-ghi");
+    // equal-length replacement
+    s.replace_range(4..6, "ÿ");
+    assert_eq!(s, "abc-ÿ-ghi");
+    s.replace_range(0..3, "xyz");
+    assert_eq!(s, "xyz-ÿ-ghi");
+}
+
 #[test]
 #[should_panic]
-fn test_splice_char_boundary() {
+fn test_replace_range_char_boundary() {
     let mut s = "Hello, 世界!".to_owned();
-    s.splice(..8, "");
+    s.replace_range(..8, "");
 }
 
 #[test]
-fn test_splice_inclusive_range() {
+fn test_replace_range_inclusive_range() {
     let mut v = String::from("12345");
-    v.splice(2..=3, "789");
+    v.replace_range(2..=3, "789");
     assert_eq!(v, "127895");
-    v.splice(1..=2, "A");
+    v.replace_range(1..=2, "A");
     assert_eq!(v, "1A895");
 }
 
 #[test]
 #[should_panic]
-fn test_splice_out_of_bounds() {
+fn test_replace_range_out_of_bounds() {
     let mut s = String::from("12345");
-    s.splice(5..6, "789");
+    s.replace_range(5..6, "789");
 }
 
 #[test]
 #[should_panic]
-fn test_splice_inclusive_out_of_bounds() {
+fn test_replace_range_inclusive_out_of_bounds() {
     let mut s = String::from("12345");
-    s.splice(5..=5, "789");
+    s.replace_range(5..=5, "789");
 }
 
 #[test]
-fn test_splice_empty() {
+fn test_replace_range_empty() {
     let mut s = String::from("12345");
-    s.splice(1..2, "");
+    s.replace_range(1..2, "");
     assert_eq!(s, "1345");
 }
 
 #[test]
-fn test_splice_unbounded() {
+fn test_replace_range_unbounded() {
     let mut s = String::from("12345");
-    s.splice(.., "");
+    s.replace_range(.., "");
     assert_eq!(s, "");
 }
 
@@ -501,6 +619,24 @@ fn test_extend_ref() {
     assert_eq!(&a, "foobar");
 }
 
+#[test]
+fn test_extend_char() {
+    let mut a = String::from("a");
+    a.extend(vec!['b', 'é', '中', '😀', 'z']);
+    assert_eq!(a, "abé中😀z");
+
+    // a size hint with no useful lower bound
+    let mut b = String::new();
+    b.extend("xé中😀".chars().filter(|_| true));
+    assert_eq!(b, "xé中😀");
+
+    // a lower bound that underestimates the number of bytes
+    let mut c = String::new();
+    c.extend(::std::iter::repeat('😀').take(10));
+    assert_eq!(c.len(), 40);
+    assert!(c.chars().all(|ch| ch == '😀'));
+}
+
 #[test]
 fn test_into_boxed_str() {
     let xs = String::from("hello my name is bob");
@@ -609,6 +745,22 @@ fn test_try_reserve() {
 
 }
 
+#[test]
+fn test_try_reserve_keeps_contents() {
+    let mut s = String::from("hello");
+    assert!(s.try_reserve(100).is_ok());
+    assert!(s.capacity() >= 105);
+    assert_eq!(s, "hello");
+
+    assert!(s.try_reserve_exact(200).is_ok());
+    assert!(s.capacity() >= 205);
+    assert_eq!(s, "hello");
+
+    // a failed reservation leaves the string alone as well
+    assert!(s.try_reserve(usize::max_value()).is_err());
+    assert_eq!(s, "hello");
+}
+
 #[test]
 fn test_try_reserve_exact() {
 
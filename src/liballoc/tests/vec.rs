@@ -9,6 +9,7 @@
 // except according to those terms.
 
 use std::borrow::Cow;
+use std::collections::LinkedList;
 use std::mem::size_of;
 use std::{usize, isize};
 use std::vec::{Drain, IntoIter};
@@ -192,6 +193,34 @@ fn test_split_at_mut() {
     assert_eq!(values, [2, 3, 5, 6, 7]);
 }
 
+#[test]
+fn test_split_at_mut_both_halves() {
+    fn swap_heads(a: &mut [i32], b: &mut [i32]) {
+        ::std::mem::swap(&mut a[0], &mut b[0]);
+    }
+
+    let mut values = vec![1, 2, 3, 4, 5];
+    {
+        let (left, right) = values.split_at_mut(2);
+        swap_heads(left, right);
+        left[1] += right[1];
+    }
+    assert_eq!(values, [3, 6, 1, 4, 5]);
+
+    {
+        let (left, right) = values.split_at_mut(5);
+        assert_eq!(left.len(), 5);
+        assert!(right.is_empty());
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_split_at_mut_out_of_bounds() {
+    let mut values = vec![1, 2, 3];
+    values.split_at_mut(4);
+}
+
 #[test]
 fn test_clone() {
     let v: Vec<i32> = vec![];
@@ -227,6 +256,23 @@ fn test_clone_from() {
     assert_eq!(v, three)
 }
 
+#[test]
+fn test_hash_matches_slice() {
+    let v = vec![1, 2, 3];
+    assert_eq!(::hash(&v), ::hash(&&v[..]));
+    assert_eq!(::hash(&v), ::hash(&[1, 2, 3][..]));
+
+    let linked: LinkedList<_> = v.iter().cloned().collect();
+    assert_eq!(::hash(&v), ::hash(&linked));
+
+    // The length is hashed first, so a shared prefix does not collide.
+    let bytes = vec![0u8, 0, 0];
+    assert!(::hash(&&bytes[..2]) != ::hash(&&bytes[..]));
+    assert!(::hash(&vec![(), ()]) != ::hash(&vec![()]));
+    let empty: &[i32] = &[];
+    assert!(::hash(&empty) != ::hash(&&[0][..]));
+}
+
 #[test]
 fn test_retain() {
     let mut vec = vec![1, 2, 3, 4];
@@ -234,6 +280,58 @@ fn test_retain() {
     assert_eq!(vec, [2, 4]);
 }
 
+#[test]
+fn test_dedup_consecutive_by() {
+    let mut vec = vec![1, 1, 1, 2, 2];
+    assert_eq!(vec.dedup_consecutive_by(|a, b| a == b), 3);
+    assert_eq!(vec, [1, 2]);
+
+    // the retained element is passed first
+    let mut vec = vec![1, 2, 4, 5, 7];
+    assert_eq!(vec.dedup_consecutive_by(|a, b| b - a == 1), 2);
+    assert_eq!(vec, [1, 4, 7]);
+
+    let mut vec: Vec<i32> = vec![];
+    assert_eq!(vec.dedup_consecutive_by(|a, b| a == b), 0);
+}
+
+#[test]
+fn test_remove_item() {
+    let mut vec = vec![1, 2, 3, 2, 4];
+    assert_eq!(vec.remove_item(&2), Some(2));
+    assert_eq!(vec, [1, 3, 2, 4]);
+    assert_eq!(vec.remove_item(&1), Some(1));
+    assert_eq!(vec, [3, 2, 4]);
+    assert_eq!(vec.remove_item(&5), None);
+    assert_eq!(vec, [3, 2, 4]);
+}
+
+#[test]
+fn test_partition_in_place() {
+    let mut vec = vec![1, 2, 3, 4, 5];
+    let mid = vec.partition_in_place(|&x| x > 2);
+    assert_eq!(mid, 3);
+    assert!(vec[..mid].iter().all(|&x| x > 2));
+    assert!(vec[mid..].iter().all(|&x| x <= 2));
+
+    let mut vec: Vec<i32> = vec![];
+    assert_eq!(vec.partition_in_place(|&x| x > 2), 0);
+
+    let mut vec: Vec<i32> = (0..10).collect();
+    let mid = vec.partition_in_place(|&x| x % 2 == 0);
+    assert_eq!(mid, 5);
+    assert!(vec[..mid].iter().all(|&x| x % 2 == 0));
+    assert!(vec[mid..].iter().all(|&x| x % 2 == 1));
+    vec.sort();
+    assert!(vec.iter().cloned().eq(0..10));
+
+    let mut vec = vec![1, 2, 3];
+    assert_eq!(vec.partition_in_place(|_| true), 3);
+    assert_eq!(vec, [1, 2, 3]);
+    assert_eq!(vec.partition_in_place(|_| false), 0);
+    assert_eq!(vec, [1, 2, 3]);
+}
+
 #[test]
 fn test_dedup() {
     fn case(a: Vec<i32>, b: Vec<i32>) {
@@ -281,6 +379,21 @@ fn test_dedup_by() {
     assert_eq!(vec, [("foo", 3), ("bar", 12)]);
 }
 
+#[test]
+fn test_sort_and_dedup() {
+    let mut vec = vec![3, 1, 2, 1, 3];
+    vec.sort_and_dedup();
+    assert_eq!(vec, [1, 2, 3]);
+
+    let mut vec: Vec<i32> = vec![];
+    vec.sort_and_dedup();
+    assert!(vec.is_empty());
+
+    let mut vec = vec![1, 1, 2, 2, 2, 3];
+    vec.dedup_sorted();
+    assert_eq!(vec, [1, 2, 3]);
+}
+
 #[test]
 fn test_dedup_unique() {
     let mut v0: Vec<Box<_>> = vec![box 1, box 1, box 2, box 3];
@@ -584,6 +697,26 @@ fn test_drain_inclusive_out_of_bounds() {
     v.drain(5..=5);
 }
 
+#[test]
+fn test_insert_many() {
+    let mut v = vec![1, 2, 3, 4];
+    v.insert_many(1, vec![10, 11, 12]);
+    assert_eq!(v, [1, 10, 11, 12, 2, 3, 4]);
+
+    v.insert_many(7, (20..22).filter(|_| true));
+    assert_eq!(v, [1, 10, 11, 12, 2, 3, 4, 20, 21]);
+
+    v.insert_many(0, None);
+    assert_eq!(v, [1, 10, 11, 12, 2, 3, 4, 20, 21]);
+}
+
+#[test]
+#[should_panic]
+fn test_insert_many_out_of_bounds() {
+    let mut v = vec![1, 2, 3];
+    v.insert_many(4, Some(4));
+}
+
 #[test]
 fn test_splice() {
     let mut v = vec![1, 2, 3, 4, 5];
@@ -663,6 +796,168 @@ fn test_append() {
     assert_eq!(vec2, []);
 }
 
+#[test]
+fn test_shrink_to() {
+    let mut vec = Vec::with_capacity(100);
+    vec.extend(0..10);
+
+    // floor above the length
+    vec.shrink_to(50);
+    assert!(vec.capacity() >= 50 && vec.capacity() < 100);
+    assert!(vec.iter().cloned().eq(0..10));
+
+    // floor below the length never drops elements
+    vec.shrink_to(5);
+    assert!(vec.capacity() >= 10 && vec.capacity() < 50);
+    assert!(vec.iter().cloned().eq(0..10));
+}
+
+#[test]
+fn test_shrink_to_larger() {
+    let mut vec: Vec<i32> = Vec::with_capacity(10);
+    vec.extend(0..3);
+    let cap = vec.capacity();
+    vec.shrink_to(20);
+    assert_eq!(vec.capacity(), cap);
+    vec.shrink_to(cap);
+    assert_eq!(vec.capacity(), cap);
+    assert_eq!(vec, [0, 1, 2]);
+}
+
+#[test]
+fn test_from_fn() {
+    let vec = Vec::from_fn(4, |i| i * 2);
+    assert_eq!(vec, [0, 2, 4, 6]);
+    assert_eq!(vec.capacity(), 4);
+
+    let empty: Vec<String> = Vec::from_fn(0, |_| panic!());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_from_fn_panic_drops() {
+    use std::panic;
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let c = counter.clone();
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        Vec::from_fn(5, |i| {
+            if i == 3 {
+                panic!("boom");
+            }
+            c.clone()
+        })
+    }));
+    assert!(result.is_err());
+    // only `counter` and `c` are left; the three built elements were dropped
+    assert_eq!(Rc::strong_count(&counter), 2);
+}
+
+#[test]
+fn test_from_elem() {
+    let vec = Vec::from_elem(3, "a".to_string());
+    assert_eq!(vec, ["a", "a", "a"]);
+    assert_eq!(vec.capacity(), 3);
+
+    let empty: Vec<String> = Vec::from_elem(0, String::new());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_from_elem_panic_drops() {
+    use std::cell::Cell;
+    use std::panic;
+    use std::rc::Rc;
+
+    struct PanicOnClone<'a> {
+        clones: &'a Cell<usize>,
+        _rc: Rc<()>,
+    }
+
+    impl<'a> Clone for PanicOnClone<'a> {
+        fn clone(&self) -> Self {
+            if self.clones.get() == 3 {
+                panic!("boom");
+            }
+            self.clones.set(self.clones.get() + 1);
+            PanicOnClone { clones: self.clones, _rc: self._rc.clone() }
+        }
+    }
+
+    let counter = Rc::new(());
+    let clones = Cell::new(0);
+    let elem = PanicOnClone { clones: &clones, _rc: counter.clone() };
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| Vec::from_elem(5, elem)));
+    assert!(result.is_err());
+    assert_eq!(clones.get(), 3);
+    // the original value and its three clones were all dropped
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn test_take() {
+    let mut vec = vec![1, 2, 3, 4];
+    let cap = vec.capacity();
+    let old = vec.take();
+    assert_eq!(old, [1, 2, 3, 4]);
+    assert_eq!(old.capacity(), cap);
+    assert!(vec.is_empty());
+    assert_eq!(vec.capacity(), 0);
+}
+
+#[test]
+fn test_drain_tail_drop_order() {
+    use std::cell::RefCell;
+
+    struct Recorder<'a>(usize, &'a RefCell<Vec<usize>>);
+
+    impl<'a> Drop for Recorder<'a> {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(self.0);
+        }
+    }
+
+    let dropped = RefCell::new(Vec::new());
+    let mut vec: Vec<_> = (0..6).map(|i| Recorder(i, &dropped)).collect();
+    let cap = vec.capacity();
+
+    vec.drain_tail(2);
+    assert_eq!(vec.len(), 2);
+    assert_eq!(vec.capacity(), cap);
+    assert_eq!(*dropped.borrow(), [2, 3, 4, 5]);
+
+    vec.drain_tail(5);
+    assert_eq!(vec.len(), 2);
+    assert_eq!(dropped.borrow().len(), 4);
+}
+
+#[test]
+fn test_drain_tail_panic() {
+    use std::cell::RefCell;
+    use std::panic::{self, AssertUnwindSafe};
+
+    struct PanicOn<'a>(usize, &'a RefCell<Vec<usize>>);
+
+    impl<'a> Drop for PanicOn<'a> {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(self.0);
+            if self.0 == 3 {
+                panic!("panic in drop");
+            }
+        }
+    }
+
+    let dropped = RefCell::new(Vec::new());
+    let mut vec: Vec<_> = (0..6).map(|i| PanicOn(i, &dropped)).collect();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| vec.drain_tail(2)));
+    assert!(result.is_err());
+
+    // the elements after the panicking one are still dropped, exactly once
+    assert_eq!(vec.len(), 2);
+    assert_eq!(*dropped.borrow(), [2, 3, 4, 5]);
+}
+
 #[test]
 fn test_split_off() {
     let mut vec = vec![1, 2, 3, 4, 5, 6];
@@ -867,6 +1162,23 @@ fn drain_filter_true() {
     assert_eq!(vec, vec![]);
 }
 
+#[test]
+fn drain_filter_evens() {
+    let mut vec: Vec<i32> = (0..10).collect();
+    let evens: Vec<_> = vec.drain_filter(|x| *x % 2 == 0).collect();
+    assert_eq!(evens, [0, 2, 4, 6, 8]);
+    assert_eq!(vec, [1, 3, 5, 7, 9]);
+
+    // dropping the iterator early still removes every match
+    let mut vec: Vec<i32> = (0..10).collect();
+    {
+        let mut iter = vec.drain_filter(|x| *x % 2 == 0);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(2));
+    }
+    assert_eq!(vec, [1, 3, 5, 7, 9]);
+}
+
 #[test]
 fn drain_filter_complex() {
 
@@ -256,6 +256,18 @@ fn test_reserve() {
     assert!(d.capacity() >= 51);
 }
 
+#[test]
+fn test_shrink_to_larger() {
+    let mut d: VecDeque<i32> = VecDeque::with_capacity(10);
+    d.extend(0..3);
+    let cap = d.capacity();
+    d.shrink_to(cap + 10);
+    assert_eq!(d.capacity(), cap);
+    d.shrink_to(cap);
+    assert_eq!(d.capacity(), cap);
+    assert_eq!(d, [0, 1, 2].iter().cloned().collect::<VecDeque<_>>());
+}
+
 #[test]
 fn test_swap() {
     let mut d: VecDeque<_> = (0..5).collect();
@@ -784,6 +796,34 @@ fn test_reserve_grow() {
     }
 }
 
+#[test]
+fn test_reserve_wrapped() {
+    // head has wrapped around to the start of the buffer
+    let mut ring = VecDeque::with_capacity(7);
+    ring.extend(0..5);
+    ring.drain(..4);
+    ring.extend(5..10);
+    assert_eq!(ring, [4, 5, 6, 7, 8, 9]);
+
+    ring.reserve(10);
+    let cap = ring.capacity();
+    assert!(cap >= 16);
+    assert_eq!(ring, [4, 5, 6, 7, 8, 9]);
+
+    // all of the new capacity is usable without reallocating
+    let len = ring.len();
+    ring.extend(10..10 + cap - len);
+    assert_eq!(ring.capacity(), cap);
+    assert!(ring.iter().cloned().eq(4..10 + cap - len));
+}
+
+#[test]
+fn test_from_iter_capacity() {
+    let deq: VecDeque<_> = (0..100).collect();
+    assert!(deq.capacity() >= 100);
+    assert!(deq.iter().cloned().eq(0..100));
+}
+
 #[test]
 fn test_get() {
     let mut ring = VecDeque::new();
@@ -839,6 +879,29 @@ fn test_get_mut() {
     assert_eq!(ring.get_mut(2), None);
 }
 
+#[test]
+fn test_get_wrapped() {
+    let mut ring = VecDeque::with_capacity(7);
+    ring.extend(&[3, 4, 5, 6]);
+    ring.push_front(2);
+    ring.push_front(1);
+    let (front, back) = ring.as_slices();
+    assert!(!front.is_empty() && !back.is_empty());
+
+    let len = ring.len();
+    assert_eq!(ring.get(0), ring.front());
+    assert_eq!(ring.get(len - 1), ring.back());
+    assert_eq!(ring.get(len), None);
+    for i in 0..len {
+        assert_eq!(ring.get(i), Some(&(i + 1)));
+    }
+
+    *ring.get_mut(1).unwrap() = 20;
+    *ring.get_mut(4).unwrap() = 50;
+    assert_eq!(ring.get_mut(len), None);
+    assert_eq!(ring.into_iter().collect::<Vec<_>>(), [1, 20, 3, 4, 50, 6]);
+}
+
 #[test]
 fn test_front() {
     let mut ring = VecDeque::new();
@@ -937,6 +1000,73 @@ fn test_retain() {
     assert_eq!(&v[..], &[2, 4]);
 }
 
+#[test]
+fn test_extend_front() {
+    let mut v: VecDeque<_> = vec![4, 5].into_iter().collect();
+    v.extend_front(vec![1, 2, 3]);
+    assert_eq!(v, [1, 2, 3, 4, 5]);
+
+    // wrap around the end of the buffer
+    let mut w = VecDeque::with_capacity(7);
+    w.extend(0..6);
+    w.drain(..5);
+    w.push_back(6);
+    w.extend_front(1..5);
+    assert_eq!(w, [1, 2, 3, 4, 5, 6]);
+
+    let mut e: VecDeque<i32> = VecDeque::new();
+    e.extend_front(Vec::new());
+    assert!(e.is_empty());
+}
+
+#[test]
+fn test_rotate() {
+    // contiguous
+    let mut v: VecDeque<_> = (0..7).collect();
+    v.rotate_left(2);
+    assert_eq!(v, [2, 3, 4, 5, 6, 0, 1]);
+    assert_eq!((v.front(), v.back()), (Some(&2), Some(&1)));
+    v.rotate_right(5);
+    assert_eq!(v, [4, 5, 6, 0, 1, 2, 3]);
+    v.rotate_left(0);
+    v.rotate_right(7);
+    assert_eq!(v, [4, 5, 6, 0, 1, 2, 3]);
+
+    // wrapped around the end of the buffer
+    let mut w = VecDeque::with_capacity(7);
+    w.extend(0..6);
+    w.drain(..5);
+    w.extend(6..12);
+    assert_eq!(w, [5, 6, 7, 8, 9, 10, 11]);
+    w.rotate_left(3);
+    assert_eq!(w, [8, 9, 10, 11, 5, 6, 7]);
+    assert_eq!((w.front(), w.back()), (Some(&8), Some(&7)));
+    w.rotate_right(3);
+    assert_eq!(w, [5, 6, 7, 8, 9, 10, 11]);
+    w.rotate_right(6);
+    assert_eq!(w, [6, 7, 8, 9, 10, 11, 5]);
+
+    // every rotation amount matches the slice equivalent
+    for n in 0..10 {
+        for mid in 0..n + 1 {
+            let mut expected: Vec<_> = (0..n).collect();
+            expected.rotate_left(mid);
+            let mut d: VecDeque<_> = (0..n).collect();
+            d.rotate_left(mid);
+            assert!(d.iter().eq(expected.iter()));
+            d.rotate_right(mid);
+            assert!(d.iter().cloned().eq(0..n));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_left_out_of_bounds() {
+    let mut v: VecDeque<_> = (0..3).collect();
+    v.rotate_left(4);
+}
+
 #[test]
 fn test_extend_ref() {
     let mut v = VecDeque::new();
@@ -976,6 +1106,28 @@ fn test_contains() {
     assert!(!v.contains(&3));
 }
 
+#[test]
+fn test_binary_search() {
+    // make sure the contents wrap around the end of the buffer
+    let mut v = VecDeque::with_capacity(7);
+    v.extend(&[10, 20, 30, 40, 50]);
+    v.push_front(5);
+    v.push_front(1);
+    let (front, back) = v.as_slices();
+    assert!(!front.is_empty() && !back.is_empty());
+
+    for (i, x) in [1, 5, 10, 20, 30, 40, 50].iter().enumerate() {
+        assert_eq!(v.binary_search(x), Ok(i));
+    }
+    assert_eq!(v.binary_search(&0), Err(0));
+    assert_eq!(v.binary_search(&3), Err(1));
+    assert_eq!(v.binary_search(&7), Err(2));
+    assert_eq!(v.binary_search(&25), Err(4));
+    assert_eq!(v.binary_search(&60), Err(7));
+
+    assert_eq!(VecDeque::<i32>::new().binary_search(&1), Err(0));
+}
+
 #[allow(dead_code)]
 fn assert_covariance() {
     fn drain<'new>(d: Drain<'static, &'static str>) -> Drain<'new, &'new str> {
@@ -365,6 +365,56 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Creates a vector of length `n` whose element at index `i` is `f(i)`.
+    ///
+    /// `f` is called for each index in ascending order, and the vector
+    /// allocates exactly once. If `f` panics, the elements created so far
+    /// are dropped. To repeat a single `Clone` value, use [`from_elem`].
+    ///
+    /// [`from_elem`]: #method.from_elem
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_from_fn)]
+    ///
+    /// let vec = Vec::from_fn(4, |i| i * 2);
+    /// assert_eq!(vec, [0, 2, 4, 6]);
+    /// ```
+    #[unstable(feature = "vec_from_fn",
+               reason = "equivalent to `(0..n).map(f).collect()`",
+               issue = "0")]
+    pub fn from_fn<F>(n: usize, f: F) -> Vec<T>
+        where F: FnMut(usize) -> T
+    {
+        // `Map<Range<usize>, F>` is `TrustedLen`, so this goes through the
+        // specialized path that reserves `n` up front.
+        (0..n).map(f).collect()
+    }
+
+    /// Creates a vector of length `n` filled with clones of `value`.
+    ///
+    /// This is the function form of `vec![value; n]`: the last element is
+    /// `value` itself, and if `clone` panics, the elements created so far are
+    /// dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_from_elem)]
+    ///
+    /// let vec = Vec::from_elem(3, "a".to_string());
+    /// assert_eq!(vec, ["a", "a", "a"]);
+    /// ```
+    #[unstable(feature = "vec_from_elem",
+               reason = "equivalent to `vec![value; n]`",
+               issue = "0")]
+    pub fn from_elem(n: usize, value: T) -> Vec<T>
+        where T: Clone
+    {
+        from_elem(value, n)
+    }
+
     /// Creates a `Vec<T>` directly from the raw components of another vector.
     ///
     /// # Safety
@@ -591,8 +641,8 @@ impl<T> Vec<T> {
     /// The capacity will remain at least as large as both the length
     /// and the supplied value.
     ///
-    /// Panics if the current capacity is smaller than the supplied
-    /// minimum capacity.
+    /// If the current capacity is less than or equal to the lower limit, this
+    /// is a no-op.
     ///
     /// # Examples
     ///
@@ -608,7 +658,9 @@ impl<T> Vec<T> {
     /// ```
     #[unstable(feature = "shrink_to", reason = "new API", issue="0")]
     pub fn shrink_to(&mut self, min_capacity: usize) {
-        self.buf.shrink_to_fit(cmp::max(self.len, min_capacity));
+        if self.capacity() > min_capacity {
+            self.buf.shrink_to_fit(cmp::max(self.len, min_capacity));
+        }
     }
 
     /// Converts the vector into [`Box<[T]>`][owned slice].
@@ -701,6 +753,50 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Shortens the vector like [`truncate`], but drops the removed elements
+    /// in ascending index order instead of from the back.
+    ///
+    /// This matters when dropping the elements has ordering side effects.
+    /// If `len` is greater than or equal to the vector's current length,
+    /// this has no effect. The capacity of the vector is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_drain_tail)]
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    /// vec.drain_tail(2);
+    /// assert_eq!(vec, [1, 2]);
+    /// assert!(vec.capacity() >= 5);
+    /// ```
+    ///
+    /// [`truncate`]: #method.truncate
+    #[unstable(feature = "vec_drain_tail",
+               reason = "the drop order is the only difference from truncate",
+               issue = "0")]
+    pub fn drain_tail(&mut self, len: usize) {
+        let old_len = self.len;
+        if len >= old_len {
+            return;
+        }
+        unsafe {
+            // Shorten the vector first, so that a panicking destructor can't
+            // cause an element to be dropped twice; the guard drops the rest.
+            self.len = len;
+            let mut guard = DropTailOnDrop {
+                ptr: self.as_mut_ptr(),
+                idx: len,
+                end: old_len,
+            };
+            while guard.idx < guard.end {
+                let idx = guard.idx;
+                guard.idx += 1;
+                ptr::drop_in_place(guard.ptr.offset(idx as isize));
+            }
+        }
+    }
+
     /// Extracts a slice containing the entire vector.
     ///
     /// Equivalent to `&s[..]`.
@@ -855,6 +951,40 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Inserts all elements of `iterable` at position `index` within the
+    /// vector, keeping their order and shifting all elements after them to
+    /// the right.
+    ///
+    /// This is equivalent to `splice(index..index, iterable)`. Rather than
+    /// shifting the tail once per element, room is made for the iterator's
+    /// lower size hint up front. If the hint is exact, the tail is moved only
+    /// once; if it underestimates (as with `filter`), the remaining elements
+    /// are collected and the tail is moved a second time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_insert_many)]
+    ///
+    /// let mut vec = vec![1, 5, 6];
+    /// vec.insert_many(1, vec![2, 3, 4]);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5, 6]);
+    /// vec.insert_many(6, 7..9);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    #[unstable(feature = "vec_insert_many",
+               reason = "overlaps with `splice`; may be dropped in its favor",
+               issue = "0")]
+    pub fn insert_many<I>(&mut self, index: usize, iterable: I)
+        where I: IntoIterator<Item = T>
+    {
+        self.splice(index..index, iterable);
+    }
+
     /// Removes and returns the element at position `index` within the vector,
     /// shifting all elements after it to the left.
     ///
@@ -911,6 +1041,48 @@ impl<T> Vec<T> {
         self.drain_filter(|x| !f(x));
     }
 
+    /// Reorders the elements of the vector so that all elements for which
+    /// `pred` returns `true` precede all elements for which it returns
+    /// `false`, and returns the number of elements that returned `true`.
+    ///
+    /// This method operates in place and does not allocate. The relative
+    /// order of the elements is not preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_partition_in_place)]
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    /// let mid = vec.partition_in_place(|&x| x % 2 == 0);
+    /// assert_eq!(mid, 2);
+    /// assert!(vec[..mid].iter().all(|&x| x % 2 == 0));
+    /// assert!(vec[mid..].iter().all(|&x| x % 2 != 0));
+    /// ```
+    #[unstable(feature = "vec_partition_in_place",
+               reason = "the order of the partitioned halves is not yet guaranteed",
+               issue = "0")]
+    pub fn partition_in_place<F>(&mut self, mut pred: F) -> usize
+        where F: FnMut(&T) -> bool
+    {
+        let mut left = 0;
+        let mut right = self.len();
+        loop {
+            while left < right && pred(&self[left]) {
+                left += 1;
+            }
+            while left < right && !pred(&self[right - 1]) {
+                right -= 1;
+            }
+            if left == right {
+                return left;
+            }
+            self.swap(left, right - 1);
+            left += 1;
+            right -= 1;
+        }
+    }
+
     /// Removes all but the first of consecutive elements in the vector that resolve to the same
     /// key.
     ///
@@ -1037,6 +1209,40 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Removes all but the first of consecutive elements in the vector
+    /// satisfying a given equality relation, and returns the number of
+    /// elements removed.
+    ///
+    /// `same` is called with the most recently retained element and the
+    /// element following it, in that order; if it returns `true`, the latter
+    /// is removed. This is otherwise identical to [`dedup_by`], and is
+    /// likewise safe against `same` panicking.
+    ///
+    /// [`dedup_by`]: #method.dedup_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_dedup_count)]
+    ///
+    /// let mut vec = vec![1, 1, 1, 2, 2];
+    ///
+    /// let removed = vec.dedup_consecutive_by(|a, b| a == b);
+    ///
+    /// assert_eq!(removed, 3);
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    #[unstable(feature = "vec_dedup_count",
+               reason = "returning the number of removed elements is not yet settled",
+               issue = "0")]
+    pub fn dedup_consecutive_by<F>(&mut self, mut same: F) -> usize
+        where F: FnMut(&T, &T) -> bool
+    {
+        let len = self.len();
+        self.dedup_by(|next, prev| same(prev, next));
+        len - self.len()
+    }
+
     /// Appends an element to the back of a collection.
     ///
     /// # Panics
@@ -1214,6 +1420,35 @@ impl<T> Vec<T> {
         self.truncate(0)
     }
 
+    /// Takes the contents of the vector, leaving an empty vector in its place.
+    ///
+    /// Unlike [`clear`], the allocation moves to the returned vector; the
+    /// vector left behind is the same as one created by `Vec::new`, and so
+    /// does not own any allocation.
+    ///
+    /// [`clear`]: #method.clear
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_take)]
+    ///
+    /// let mut v = vec![1, 2, 3];
+    ///
+    /// let old = v.take();
+    ///
+    /// assert_eq!(old, [1, 2, 3]);
+    /// assert!(v.is_empty());
+    /// assert_eq!(v.capacity(), 0);
+    /// ```
+    #[inline]
+    #[unstable(feature = "vec_take",
+               reason = "equivalent to `mem::replace` with an empty vector",
+               issue = "0")]
+    pub fn take(&mut self) -> Vec<T> {
+        mem::replace(self, Vec::new())
+    }
+
     /// Returns the number of elements in the vector, also referred to
     /// as its 'length'.
     ///
@@ -1464,6 +1699,24 @@ impl<'a> Drop for SetLenOnDrop<'a> {
     }
 }
 
+// Drops the elements in `idx..end` that `drain_tail` has not gotten to yet,
+// so that they are not leaked when one of the destructors panics.
+struct DropTailOnDrop<T> {
+    ptr: *mut T,
+    idx: usize,
+    end: usize,
+}
+
+impl<T> Drop for DropTailOnDrop<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let rest = slice::from_raw_parts_mut(self.ptr.offset(self.idx as isize),
+                                                 self.end - self.idx);
+            ptr::drop_in_place(rest);
+        }
+    }
+}
+
 impl<T: PartialEq> Vec<T> {
     /// Removes consecutive repeated elements in the vector.
     ///
@@ -1484,6 +1737,33 @@ impl<T: PartialEq> Vec<T> {
         self.dedup_by(|a, b| a == b)
     }
 
+    /// Removes all duplicate elements from a sorted vector.
+    ///
+    /// This is the same operation as [`dedup`], but states the contract that
+    /// the vector is already sorted, in which case no duplicates remain
+    /// afterwards. If the vector is not sorted, only consecutive repeated
+    /// elements are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_sort_dedup)]
+    /// let mut vec = vec![1, 1, 2, 3, 3, 3];
+    ///
+    /// vec.dedup_sorted();
+    ///
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    ///
+    /// [`dedup`]: #method.dedup
+    #[unstable(feature = "vec_sort_dedup",
+               reason = "may be replaced by a sorted set collection",
+               issue = "0")]
+    #[inline]
+    pub fn dedup_sorted(&mut self) {
+        self.dedup()
+    }
+
     /// Removes the first instance of `item` from the vector if the item exists.
     ///
     /// # Examples
@@ -1503,6 +1783,31 @@ impl<T: PartialEq> Vec<T> {
     }
 }
 
+impl<T: Ord> Vec<T> {
+    /// Sorts the vector and removes all duplicate elements.
+    ///
+    /// The remaining elements are in ascending order and each appears only
+    /// once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_sort_dedup)]
+    /// let mut vec = vec![3, 1, 2, 1, 3];
+    ///
+    /// vec.sort_and_dedup();
+    ///
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[unstable(feature = "vec_sort_dedup",
+               reason = "may be replaced by a sorted set collection",
+               issue = "0")]
+    pub fn sort_and_dedup(&mut self) {
+        self.sort();
+        self.dedup();
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Internal methods and functions
 ////////////////////////////////////////////////////////////////////////////////
@@ -683,8 +683,8 @@ impl<T> VecDeque<T> {
     /// The capacity will remain at least as large as both the length
     /// and the supplied value.
     ///
-    /// Panics if the current capacity is smaller than the supplied
-    /// minimum capacity.
+    /// If the current capacity is less than or equal to the lower limit, this
+    /// is a no-op.
     ///
     /// # Examples
     ///
@@ -702,8 +702,6 @@ impl<T> VecDeque<T> {
     /// ```
     #[unstable(feature = "shrink_to", reason = "new API", issue="0")]
     pub fn shrink_to(&mut self, min_capacity: usize) {
-        assert!(self.capacity() >= min_capacity, "Tried to shrink to a larger capacity");
-
         // +1 since the ringbuffer always leaves one space empty
         // len + 1 can't overflow for an existing, well-formed ringbuffer.
         let target_cap = cmp::max(
@@ -1072,6 +1070,45 @@ impl<T> VecDeque<T> {
         a.contains(x) || b.contains(x)
     }
 
+    /// Binary searches this sorted `VecDeque` for a given element.
+    ///
+    /// If the value is found then `Ok` is returned, containing the
+    /// index of the matching element; if the value is not found then
+    /// `Err` is returned, containing the index where a matching
+    /// element could be inserted while maintaining sorted order.
+    /// Indices are logical, i.e. relative to the front of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_binary_search)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let deque: VecDeque<_> = vec![0, 1, 1, 2, 3, 5, 8, 13].into_iter().collect();
+    ///
+    /// assert_eq!(deque.binary_search(&8), Ok(6));
+    /// assert_eq!(deque.binary_search(&4), Err(5));
+    /// assert_eq!(deque.binary_search(&100), Err(8));
+    /// let r = deque.binary_search(&1);
+    /// assert!(match r { Ok(1...2) => true, _ => false, });
+    /// ```
+    #[unstable(feature = "vecdeque_binary_search",
+               reason = "should probably mirror the slice API through `as_slices`",
+               issue = "0")]
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+        where T: Ord
+    {
+        let (front, back) = self.as_slices();
+        match back.first().map(|elem| elem.cmp(x)) {
+            Some(Ordering::Less) | Some(Ordering::Equal) => {
+                back.binary_search(x)
+                    .map(|idx| idx + front.len())
+                    .map_err(|idx| idx + front.len())
+            }
+            _ => front.binary_search(x),
+        }
+    }
+
     /// Provides a reference to the front element, or `None` if the `VecDeque` is
     /// empty.
     ///
@@ -1839,6 +1876,38 @@ impl<T> VecDeque<T> {
         self.extend(other.drain(..));
     }
 
+    /// Prepends the elements of `iter` to the `VecDeque`, preserving their
+    /// order.
+    ///
+    /// The first element yielded by the iterator ends up at the front of
+    /// the buffer, followed by the rest in iteration order, followed by the
+    /// previous contents of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(extend_front)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = vec![4, 5].into_iter().collect();
+    /// buf.extend_front(vec![1, 2, 3]);
+    /// assert_eq!(buf, [1, 2, 3, 4, 5]);
+    /// ```
+    #[unstable(feature = "extend_front",
+               reason = "the resulting order of the prepended elements is under discussion",
+               issue = "0")]
+    pub fn extend_front<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let old_len = self.len();
+        self.extend(iter);
+        // Move the new elements from the back to the front one at a time;
+        // the capacity is already sufficient, so this never reallocates.
+        for _ in old_len..self.len() {
+            let elem = self.pop_back().unwrap();
+            self.push_front(elem);
+        }
+    }
+
     /// Retains only the elements specified by the predicate.
     ///
     /// In other words, remove all elements `e` such that `f(&e)` returns false.
@@ -1873,6 +1942,122 @@ impl<T> VecDeque<T> {
         }
     }
 
+    /// Rotates the double-ended queue `mid` places to the left.
+    ///
+    /// Equivalently,
+    /// - Rotates item `mid` into the first position.
+    /// - Pops the first `mid` items and pushes them to the end.
+    /// - Rotates `len() - mid` places to the right.
+    ///
+    /// # Panics
+    ///
+    /// If `mid` is greater than `len()`. Note that `mid == len()`
+    /// does _not_ panic and is a no-op rotation.
+    ///
+    /// # Complexity
+    ///
+    /// Takes `O(min(mid, len() - mid))` time and no extra space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_rotate)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = (0..10).collect();
+    ///
+    /// buf.rotate_left(3);
+    /// assert_eq!(buf, [3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
+    ///
+    /// for i in 1..10 {
+    ///     assert_eq!(i * 3 % 10, buf[0]);
+    ///     buf.rotate_left(3);
+    /// }
+    /// assert_eq!(buf, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    #[unstable(feature = "vecdeque_rotate",
+               reason = "naming may be aligned with `slice::rotate_left`/`rotate_right`",
+               issue = "0")]
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len());
+        let k = self.len() - mid;
+        if mid <= k {
+            unsafe { self.rotate_left_inner(mid) }
+        } else {
+            unsafe { self.rotate_right_inner(k) }
+        }
+    }
+
+    /// Rotates the double-ended queue `k` places to the right.
+    ///
+    /// Equivalently,
+    /// - Rotates the first item into position `k`.
+    /// - Pops the last `k` items and pushes them to the front.
+    /// - Rotates `len() - k` places to the left.
+    ///
+    /// # Panics
+    ///
+    /// If `k` is greater than `len()`. Note that `k == len()`
+    /// does _not_ panic and is a no-op rotation.
+    ///
+    /// # Complexity
+    ///
+    /// Takes `O(min(k, len() - k))` time and no extra space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_rotate)]
+    ///
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = (0..10).collect();
+    ///
+    /// buf.rotate_right(3);
+    /// assert_eq!(buf, [7, 8, 9, 0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// for i in 1..10 {
+    ///     assert_eq!(0, buf[i * 3 % 10]);
+    ///     buf.rotate_right(3);
+    /// }
+    /// assert_eq!(buf, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    #[unstable(feature = "vecdeque_rotate",
+               reason = "naming may be aligned with `slice::rotate_left`/`rotate_right`",
+               issue = "0")]
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len());
+        let mid = self.len() - k;
+        if k <= mid {
+            unsafe { self.rotate_right_inner(k) }
+        } else {
+            unsafe { self.rotate_left_inner(mid) }
+        }
+    }
+
+    // Safety: the following two methods require that the rotation amount
+    // be at most half the length of the deque.
+    //
+    // `wrap_copy` requires that `min(x, cap() - x) + copy_len <= cap()`,
+    // but that `min` is never more than half the capacity, regardless of x,
+    // so it's sound to call here because we're calling with something
+    // at most half the length, which is always below half the capacity.
+
+    unsafe fn rotate_left_inner(&mut self, mid: usize) {
+        debug_assert!(mid * 2 <= self.len());
+        self.wrap_copy(self.head, self.tail, mid);
+        self.head = self.wrap_add(self.head, mid);
+        self.tail = self.wrap_add(self.tail, mid);
+    }
+
+    unsafe fn rotate_right_inner(&mut self, k: usize) {
+        debug_assert!(k * 2 <= self.len());
+        self.head = self.wrap_sub(self.head, k);
+        self.tail = self.wrap_sub(self.tail, k);
+        self.wrap_copy(self.tail, self.head, k);
+    }
+
     // This may panic or abort
     #[inline]
     fn grow_if_necessary(&mut self) {
@@ -79,6 +79,11 @@ pub trait SliceExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn split_at(&self, mid: usize) -> (&[Self::Item], &[Self::Item]);
 
+    #[unstable(feature = "slice_split_at_checked",
+               reason = "may be replaced by a `get`-style range API",
+               issue = "0")]
+    fn split_at_checked(&self, mid: usize) -> Option<(&[Self::Item], &[Self::Item])>;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn iter(&self) -> Iter<Self::Item>;
 
@@ -101,12 +106,23 @@ pub trait SliceExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn windows(&self, size: usize) -> Windows<Self::Item>;
 
+    #[unstable(feature = "slice_pairwise_mut",
+               reason = "may be generalized to windows of arbitrary size",
+               issue = "0")]
+    fn pairwise_mut<F>(&mut self, f: F)
+        where F: FnMut(&mut Self::Item, &mut Self::Item);
+
     #[stable(feature = "core", since = "1.6.0")]
     fn chunks(&self, size: usize) -> Chunks<Self::Item>;
 
     #[unstable(feature = "exact_chunks", issue = "47115")]
     fn exact_chunks(&self, size: usize) -> ExactChunks<Self::Item>;
 
+    #[unstable(feature = "rchunks",
+               reason = "the placement of the remainder chunk is still under discussion",
+               issue = "0")]
+    fn rchunks(&self, size: usize) -> RChunks<Self::Item>;
+
     #[stable(feature = "core", since = "1.6.0")]
     fn get<I>(&self, index: I) -> Option<&I::Output>
         where I: SliceIndex<Self>;
@@ -226,6 +242,16 @@ pub trait SliceExt {
     #[unstable(feature = "swap_with_slice", issue = "44030")]
     fn swap_with_slice(&mut self, src: &mut [Self::Item]);
 
+    #[unstable(feature = "slice_fill",
+               reason = "the `Clone` bound may be relaxed or specialized for `Copy`",
+               issue = "0")]
+    fn fill(&mut self, value: Self::Item) where Self::Item: Clone;
+
+    #[unstable(feature = "slice_fill",
+               reason = "the `Clone` bound may be relaxed or specialized for `Copy`",
+               issue = "0")]
+    fn fill_with<F>(&mut self, f: F) where F: FnMut() -> Self::Item;
+
     #[stable(feature = "sort_unstable", since = "1.20.0")]
     fn sort_unstable(&mut self)
         where Self::Item: Ord;
@@ -289,6 +315,15 @@ impl<T> SliceExt for [T] {
         (&self[..mid], &self[mid..])
     }
 
+    #[inline]
+    fn split_at_checked(&self, mid: usize) -> Option<(&[T], &[T])> {
+        if mid <= self.len() {
+            Some(self.split_at(mid))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn iter(&self) -> Iter<T> {
         unsafe {
@@ -356,6 +391,16 @@ impl<T> SliceExt for [T] {
         Windows { v: self, size: size }
     }
 
+    #[inline]
+    fn pairwise_mut<F>(&mut self, mut f: F)
+        where F: FnMut(&mut T, &mut T)
+    {
+        for i in 1..self.len() {
+            let (left, right) = self.split_at_mut(i);
+            f(&mut left[i - 1], &mut right[0]);
+        }
+    }
+
     #[inline]
     fn chunks(&self, chunk_size: usize) -> Chunks<T> {
         assert!(chunk_size != 0);
@@ -367,7 +412,14 @@ impl<T> SliceExt for [T] {
         assert!(chunk_size != 0);
         let rem = self.len() % chunk_size;
         let len = self.len() - rem;
-        ExactChunks { v: &self[..len], chunk_size: chunk_size}
+        let (fst, snd) = self.split_at(len);
+        ExactChunks { v: fst, rem: snd, chunk_size: chunk_size}
+    }
+
+    #[inline]
+    fn rchunks(&self, chunk_size: usize) -> RChunks<T> {
+        assert!(chunk_size != 0);
+        RChunks { v: self, chunk_size: chunk_size }
     }
 
     #[inline]
@@ -558,7 +610,8 @@ impl<T> SliceExt for [T] {
         assert!(chunk_size != 0);
         let rem = self.len() % chunk_size;
         let len = self.len() - rem;
-        ExactChunksMut { v: &mut self[..len], chunk_size: chunk_size}
+        let (fst, snd) = self.split_at_mut(len);
+        ExactChunksMut { v: fst, rem: snd, chunk_size: chunk_size}
     }
 
     #[inline]
@@ -724,6 +777,24 @@ impl<T> SliceExt for [T] {
         }
     }
 
+    #[inline]
+    fn fill(&mut self, value: T) where T: Clone {
+        // The last slot takes `value` itself, which saves a clone.
+        if let Some((last, elems)) = self.split_last_mut() {
+            for el in elems {
+                el.clone_from(&value);
+            }
+            *last = value;
+        }
+    }
+
+    #[inline]
+    fn fill_with<F>(&mut self, mut f: F) where F: FnMut() -> T {
+        for el in self {
+            *el = f();
+        }
+    }
+
     #[inline]
     fn binary_search_by_key<'a, B, F>(&'a self, b: &B, mut f: F) -> Result<usize, usize>
         where F: FnMut(&'a Self::Item) -> B,
@@ -2441,25 +2512,39 @@ unsafe impl<'a, T> TrustedRandomAccess for ChunksMut<'a, T> {
 /// time).
 ///
 /// When the slice len is not evenly divided by the chunk size, the last
-/// up to `chunk_size-1` elements will be omitted.
+/// up to `chunk_size-1` elements will be omitted but can be retrieved from
+/// the [`remainder`] function from the iterator.
 ///
 /// This struct is created by the [`exact_chunks`] method on [slices].
 ///
 /// [`exact_chunks`]: ../../std/primitive.slice.html#method.exact_chunks
+/// [`remainder`]: ../../std/slice/struct.ExactChunks.html#method.remainder
 /// [slices]: ../../std/primitive.slice.html
 #[derive(Debug)]
 #[unstable(feature = "exact_chunks", issue = "47115")]
 pub struct ExactChunks<'a, T:'a> {
     v: &'a [T],
+    rem: &'a [T],
     chunk_size: usize
 }
 
+#[unstable(feature = "exact_chunks", issue = "47115")]
+impl<'a, T> ExactChunks<'a, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `chunk_size-1`
+    /// elements.
+    pub fn remainder(&self) -> &'a [T] {
+        self.rem
+    }
+}
+
 // FIXME(#26925) Remove in favor of `#[derive(Clone)]`
 #[unstable(feature = "exact_chunks", issue = "47115")]
 impl<'a, T> Clone for ExactChunks<'a, T> {
     fn clone(&self) -> ExactChunks<'a, T> {
         ExactChunks {
             v: self.v,
+            rem: self.rem,
             chunk_size: self.chunk_size,
         }
     }
@@ -2545,19 +2630,32 @@ unsafe impl<'a, T> TrustedRandomAccess for ExactChunks<'a, T> {
 
 /// An iterator over a slice in (non-overlapping) mutable chunks (`chunk_size`
 /// elements at a time). When the slice len is not evenly divided by the chunk
-/// size, the last up to `chunk_size-1` elements will be omitted.
+/// size, the last up to `chunk_size-1` elements will be omitted but can be
+/// retrieved from the [`into_remainder`] function from the iterator.
 ///
 /// This struct is created by the [`exact_chunks_mut`] method on [slices].
 ///
 /// [`exact_chunks_mut`]: ../../std/primitive.slice.html#method.exact_chunks_mut
+/// [`into_remainder`]: ../../std/slice/struct.ExactChunksMut.html#method.into_remainder
 /// [slices]: ../../std/primitive.slice.html
 #[derive(Debug)]
 #[unstable(feature = "exact_chunks", issue = "47115")]
 pub struct ExactChunksMut<'a, T:'a> {
     v: &'a mut [T],
+    rem: &'a mut [T],
     chunk_size: usize
 }
 
+#[unstable(feature = "exact_chunks", issue = "47115")]
+impl<'a, T> ExactChunksMut<'a, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `chunk_size-1`
+    /// elements.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.rem
+    }
+}
+
 #[unstable(feature = "exact_chunks", issue = "47115")]
 impl<'a, T> Iterator for ExactChunksMut<'a, T> {
     type Item = &'a mut [T];
@@ -2640,6 +2738,145 @@ unsafe impl<'a, T> TrustedRandomAccess for ExactChunksMut<'a, T> {
     fn may_have_side_effect() -> bool { false }
 }
 
+/// An iterator over a slice in (non-overlapping) chunks (`chunk_size` elements at a
+/// time), starting at the end of the slice.
+///
+/// When the slice len is not evenly divided by the chunk size, the last slice
+/// of the iteration will be the remainder, taken from the start of the slice.
+///
+/// This struct is created by the [`rchunks`] method on [slices].
+///
+/// [`rchunks`]: ../../std/primitive.slice.html#method.rchunks
+/// [slices]: ../../std/primitive.slice.html
+#[derive(Debug)]
+#[unstable(feature = "rchunks",
+           reason = "the placement of the remainder chunk is still under discussion",
+           issue = "0")]
+pub struct RChunks<'a, T:'a> {
+    v: &'a [T],
+    chunk_size: usize
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "rchunks",
+           reason = "the placement of the remainder chunk is still under discussion",
+           issue = "0")]
+impl<'a, T> Clone for RChunks<'a, T> {
+    fn clone(&self) -> RChunks<'a, T> {
+        RChunks {
+            v: self.v,
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+#[unstable(feature = "rchunks",
+           reason = "the placement of the remainder chunk is still under discussion",
+           issue = "0")]
+impl<'a, T> Iterator for RChunks<'a, T> {
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let chunksz = cmp::min(self.v.len(), self.chunk_size);
+            let (fst, snd) = self.v.split_at(self.v.len() - chunksz);
+            self.v = fst;
+            Some(snd)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.v.is_empty() {
+            (0, Some(0))
+        } else {
+            let n = self.v.len() / self.chunk_size;
+            let rem = self.v.len() % self.chunk_size;
+            let n = if rem > 0 { n+1 } else { n };
+            (n, Some(n))
+        }
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (end, overflow) = n.overflowing_mul(self.chunk_size);
+        if end >= self.v.len() || overflow {
+            self.v = &[];
+            None
+        } else {
+            // `end` is counted from the back of the slice
+            let end = self.v.len() - end;
+            let start = match end.checked_sub(self.chunk_size) {
+                Some(start) => start,
+                None => 0,
+            };
+            let nth = &self.v[start..end];
+            self.v = &self.v[..start];
+            Some(nth)
+        }
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let rem = self.v.len() % self.chunk_size;
+            let end = if rem == 0 { self.chunk_size } else { rem };
+            Some(&self.v[..end])
+        }
+    }
+}
+
+#[unstable(feature = "rchunks",
+           reason = "the placement of the remainder chunk is still under discussion",
+           issue = "0")]
+impl<'a, T> DoubleEndedIterator for RChunks<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.v.is_empty() {
+            None
+        } else {
+            let remainder = self.v.len() % self.chunk_size;
+            let chunksz = if remainder != 0 { remainder } else { self.chunk_size };
+            let (fst, snd) = self.v.split_at(chunksz);
+            self.v = snd;
+            Some(fst)
+        }
+    }
+}
+
+#[unstable(feature = "rchunks",
+           reason = "the placement of the remainder chunk is still under discussion",
+           issue = "0")]
+impl<'a, T> ExactSizeIterator for RChunks<'a, T> {}
+
+#[unstable(feature = "rchunks",
+           reason = "the placement of the remainder chunk is still under discussion",
+           issue = "0")]
+impl<'a, T> FusedIterator for RChunks<'a, T> {}
+
+#[doc(hidden)]
+unsafe impl<'a, T> TrustedRandomAccess for RChunks<'a, T> {
+    unsafe fn get_unchecked(&mut self, i: usize) -> &'a [T] {
+        let end = self.v.len() - i * self.chunk_size;
+        let start = match end.checked_sub(self.chunk_size) {
+            None => 0,
+            Some(start) => start,
+        };
+        from_raw_parts(self.v.as_ptr().offset(start as isize), end - start)
+    }
+    fn may_have_side_effect() -> bool { false }
+}
+
 //
 // Free functions
 //
@@ -15,13 +15,13 @@
 #![stable(feature = "rust1", since = "1.0.0")]
 
 use self::pattern::Pattern;
-use self::pattern::{Searcher, ReverseSearcher, DoubleEndedSearcher};
+use self::pattern::{Searcher, SearchStep, ReverseSearcher, DoubleEndedSearcher};
 
 use char;
 use fmt;
 use iter::{Map, Cloned, FusedIterator, TrustedLen};
 use iter_private::TrustedRandomAccess;
-use slice::{self, SliceIndex};
+use slice::{self, memchr, SliceIndex};
 use mem;
 
 pub mod pattern;
@@ -1438,6 +1438,88 @@ impl<'a> DoubleEndedIterator for LinesAny<'a> {
 #[allow(deprecated)]
 impl<'a> FusedIterator for LinesAny<'a> {}
 
+/// Creates an iterator over the lines of a byte slice, as byte slices.
+///
+/// Lines are ended with either a newline (`\n`) or a carriage return with
+/// a line feed (`\r\n`); the terminator is not included in the yielded
+/// lines. The final line ending is optional. Unlike [`str::lines`], the
+/// input does not need to be valid UTF-8.
+///
+/// [`str::lines`]: ../../std/primitive.str.html#method.lines
+///
+/// # Examples
+///
+/// ```
+/// #![feature(str_byte_lines)]
+/// use std::str;
+///
+/// let data = b"foo\r\nb\xFFr\nbaz\n";
+/// let lines: Vec<&[u8]> = str::byte_lines(data).collect();
+///
+/// assert_eq!(lines, [&b"foo"[..], &b"b\xFFr"[..], &b"baz"[..]]);
+/// ```
+#[unstable(feature = "str_byte_lines",
+           reason = "may be folded into a general byte-string API",
+           issue = "0")]
+#[inline]
+pub fn byte_lines(data: &[u8]) -> ByteLines {
+    ByteLines { bytes: data }
+}
+
+/// An iterator over the lines of a byte slice.
+///
+/// This struct is created by the [`byte_lines`] function.
+/// See its documentation for more.
+///
+/// [`byte_lines`]: fn.byte_lines.html
+#[unstable(feature = "str_byte_lines",
+           reason = "may be folded into a general byte-string API",
+           issue = "0")]
+#[derive(Clone, Debug)]
+pub struct ByteLines<'a> {
+    bytes: &'a [u8],
+}
+
+#[unstable(feature = "str_byte_lines",
+           reason = "may be folded into a general byte-string API",
+           issue = "0")]
+impl<'a> Iterator for ByteLines<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+        let line = match memchr::memchr(b'\n', self.bytes) {
+            Some(index) => {
+                let line = &self.bytes[..index];
+                self.bytes = &self.bytes[index + 1..];
+                line
+            }
+            None => mem::replace(&mut self.bytes, &[]),
+        };
+        match line.split_last() {
+            Some((&b'\r', rest)) => Some(rest),
+            _ => Some(line),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.bytes.is_empty() {
+            (0, Some(0))
+        } else {
+            (1, Some(self.bytes.len()))
+        }
+    }
+}
+
+#[unstable(feature = "str_byte_lines",
+           reason = "may be folded into a general byte-string API",
+           issue = "0")]
+impl<'a> FusedIterator for ByteLines<'a> {}
+
 /*
 Section: UTF-8 validation
 */
@@ -2180,6 +2262,11 @@ pub trait StrExt {
     #[stable(feature = "core", since = "1.6.0")]
     fn ends_with<'a, P: Pattern<'a>>(&'a self, pat: P) -> bool
         where P::Searcher: ReverseSearcher<'a>;
+    #[unstable(feature = "str_strip", reason = "newly added", issue = "0")]
+    fn strip_prefix<'a, P: Pattern<'a>>(&'a self, prefix: P) -> Option<&'a str>;
+    #[unstable(feature = "str_strip", reason = "newly added", issue = "0")]
+    fn strip_suffix<'a, P: Pattern<'a>>(&'a self, suffix: P) -> Option<&'a str>
+        where P::Searcher: ReverseSearcher<'a>;
     #[stable(feature = "core", since = "1.6.0")]
     fn trim_matches<'a, P: Pattern<'a>>(&'a self, pat: P) -> &'a str
         where P::Searcher: DoubleEndedSearcher<'a>;
@@ -2404,6 +2491,30 @@ impl StrExt for str {
         pat.is_suffix_of(self)
     }
 
+    #[inline]
+    fn strip_prefix<'a, P: Pattern<'a>>(&'a self, prefix: P) -> Option<&'a str> {
+        match prefix.into_searcher(self).next() {
+            SearchStep::Match(0, end) => unsafe {
+                // Searcher is known to return valid indices
+                Some(self.slice_unchecked(end, self.len()))
+            },
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn strip_suffix<'a, P: Pattern<'a>>(&'a self, suffix: P) -> Option<&'a str>
+        where P::Searcher: ReverseSearcher<'a>
+    {
+        match suffix.into_searcher(self).next_back() {
+            SearchStep::Match(start, end) if end == self.len() => unsafe {
+                // Searcher is known to return valid indices
+                Some(self.slice_unchecked(0, start))
+            },
+            _ => None,
+        }
+    }
+
     #[inline]
     fn trim_matches<'a, P: Pattern<'a>>(&'a self, pat: P) -> &'a str
         where P::Searcher: DoubleEndedSearcher<'a>
@@ -112,6 +112,14 @@ fn test_eq_ignore_ascii_case() {
     assert!(!"K".eq_ignore_ascii_case("k"));
     assert!(!"ß".eq_ignore_ascii_case("s"));
 
+    assert!("HeLLo".eq_ignore_ascii_case("hello"));
+    assert!(!"hello".eq_ignore_ascii_case("hell"));
+    assert!(!"hell".eq_ignore_ascii_case("hello"));
+    assert!("".eq_ignore_ascii_case(""));
+    // Non-ASCII bytes are compared exactly, even within multibyte sequences.
+    assert!("Straße".eq_ignore_ascii_case("STRAßE"));
+    assert!(!"é".eq_ignore_ascii_case("É"));
+
     for i in 0..501 {
         let lower = if 'A' as u32 <= i && i <= 'Z' as u32 { i + 'a' as u32 - 'A' as u32 }
                     else { i };
@@ -268,6 +268,25 @@ fn test_hash_idempotent() {
     assert_eq!(hash(&val32), hash(&val32));
 }
 
+#[test]
+fn test_hash_with_keys() {
+    let val = "the quick brown fox";
+    let (k0, k1) = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+
+    assert_eq!(hash_with(SipHasher::new_with_keys(k0, k1), &val),
+               hash_with(SipHasher::new_with_keys(k0, k1), &val));
+    assert_eq!(hash_with(SipHasher13::new_with_keys(k0, k1), &val),
+               hash_with(SipHasher13::new_with_keys(k0, k1), &val));
+
+    assert!(hash_with(SipHasher::new_with_keys(k0, k1), &val) !=
+            hash_with(SipHasher::new_with_keys(k1, k0), &val));
+    assert!(hash_with(SipHasher13::new_with_keys(k0, k1), &val) !=
+            hash_with(SipHasher13::new_with_keys(k0, k1 + 1), &val));
+
+    // `new` is the same as all-zero keys
+    assert_eq!(hash_with(SipHasher::new_with_keys(0, 0), &val), hash(&val));
+}
+
 #[test]
 fn test_hash_no_bytes_dropped_64() {
     let val = 0xdeadbeef_deadbeef_u64;
@@ -35,9 +35,13 @@
 #![feature(pattern)]
 #![feature(range_is_empty)]
 #![feature(raw)]
+#![feature(rchunks)]
 #![feature(refcell_replace_swap)]
+#![feature(slice_fill)]
+#![feature(slice_pairwise_mut)]
 #![feature(slice_patterns)]
 #![feature(slice_rotate)]
+#![feature(slice_split_at_checked)]
 #![feature(sort_internals)]
 #![feature(specialization)]
 #![feature(step_trait)]
@@ -167,6 +167,81 @@ fn test_chunks_zip() {
     assert_eq!(res, vec![14, 22, 14]);
 }
 
+#[test]
+fn test_rchunks() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5, 6];
+    let mut c = v.rchunks(3);
+    assert_eq!(c.len(), 3);
+    assert_eq!(c.next().unwrap(), &[4, 5, 6]);
+    assert_eq!(c.next().unwrap(), &[1, 2, 3]);
+    assert_eq!(c.next().unwrap(), &[0]);
+    assert_eq!(c.next(), None);
+
+    let c = v.rchunks(3).rev().collect::<Vec<_>>();
+    assert_eq!(c, [&[0][..], &[1, 2, 3], &[4, 5, 6]]);
+
+    let v2: &[i32] = &[];
+    assert_eq!(v2.rchunks(2).next(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_rchunks_0() {
+    let v: &[i32] = &[1, 2, 3];
+    v.rchunks(0);
+}
+
+#[test]
+fn test_rchunks_count() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5];
+    let c = v.rchunks(3);
+    assert_eq!(c.count(), 2);
+
+    let v2: &[i32] = &[0, 1, 2, 3, 4];
+    let c2 = v2.rchunks(2);
+    assert_eq!(c2.count(), 3);
+
+    let v3: &[i32] = &[];
+    let c3 = v3.rchunks(2);
+    assert_eq!(c3.count(), 0);
+}
+
+#[test]
+fn test_rchunks_nth() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5];
+    let mut c = v.rchunks(2);
+    assert_eq!(c.nth(1).unwrap(), &[2, 3]);
+    assert_eq!(c.next().unwrap(), &[0, 1]);
+
+    let v2: &[i32] = &[0, 1, 2, 3, 4];
+    let mut c2 = v2.rchunks(3);
+    assert_eq!(c2.nth(1).unwrap(), &[0, 1]);
+    assert_eq!(c2.next(), None);
+}
+
+#[test]
+fn test_rchunks_last() {
+    let v: &[i32] = &[0, 1, 2, 3, 4, 5];
+    let c = v.rchunks(2);
+    assert_eq!(c.last().unwrap(), &[0, 1]);
+
+    let v2: &[i32] = &[0, 1, 2, 3, 4];
+    let c2 = v2.rchunks(2);
+    assert_eq!(c2.last().unwrap(), &[0]);
+}
+
+#[test]
+fn test_rchunks_zip() {
+    let v1: &[i32] = &[0, 1, 2, 3, 4];
+    let v2: &[i32] = &[6, 7, 8, 9, 10];
+
+    let res = v1.rchunks(2)
+        .zip(v2.rchunks(2))
+        .map(|(a, b)| a.iter().sum::<i32>() + b.iter().sum::<i32>())
+        .collect::<Vec<_>>();
+    assert_eq!(res, vec![26, 18, 6]);
+}
+
 #[test]
 fn test_chunks_mut_count() {
     let v: &mut [i32] = &mut [0, 1, 2, 3, 4, 5];
@@ -324,6 +399,35 @@ fn test_exact_chunks_mut_zip() {
     assert_eq!(v1, [13, 14, 19, 20, 4]);
 }
 
+#[test]
+fn test_exact_chunks_mut_remainder() {
+    let v: &mut [i32] = &mut [1, 2, 3, 4, 5, 6, 7, 8];
+    {
+        let mut c = v.exact_chunks_mut(3);
+        for chunk in c.by_ref() {
+            for x in chunk {
+                *x = 0;
+            }
+        }
+        let rem = c.into_remainder();
+        assert_eq!(rem, &[7, 8]);
+        rem[0] = 70;
+    }
+    assert_eq!(v, [0, 0, 0, 0, 0, 0, 70, 8]);
+
+    let v: &mut [i32] = &mut [1, 2, 3, 4];
+    assert_eq!(v.exact_chunks_mut(2).into_remainder(), &[]);
+}
+
+#[test]
+fn test_exact_chunks_remainder() {
+    let v: &[i32] = &[0, 1, 2, 3, 4];
+    let mut c = v.exact_chunks(2);
+    assert_eq!(c.remainder(), &[4]);
+    assert_eq!(c.next().unwrap(), &[0, 1]);
+    assert_eq!(c.clone().remainder(), &[4]);
+}
+
 #[test]
 fn test_windows_count() {
     let v: &[i32] = &[0, 1, 2, 3, 4, 5];
@@ -376,6 +480,54 @@ fn test_windows_zip() {
     assert_eq!(res, [14, 18, 22, 26]);
 }
 
+#[test]
+fn test_pairwise_mut() {
+    // one bubble sort pass moves the maximum to the end
+    let v: &mut [i32] = &mut [3, 9, 1, 7, 2];
+    v.pairwise_mut(|a, b| if *a > *b { ::std::mem::swap(a, b) });
+    assert_eq!(v, [3, 1, 7, 2, 9]);
+
+    // pairs are visited left to right, seeing earlier updates
+    let v: &mut [i32] = &mut [1, 1, 1, 1];
+    v.pairwise_mut(|a, b| *b += *a);
+    assert_eq!(v, [1, 2, 3, 4]);
+
+    let mut calls = 0;
+    let v: &mut [i32] = &mut [1];
+    v.pairwise_mut(|_, _| calls += 1);
+    let v: &mut [i32] = &mut [];
+    v.pairwise_mut(|_, _| calls += 1);
+    assert_eq!(calls, 0);
+}
+
+#[test]
+fn test_fill() {
+    let mut v = [0u8; 8];
+    v[2..6].fill(0xff);
+    assert_eq!(v, [0, 0, 0xff, 0xff, 0xff, 0xff, 0, 0]);
+
+    let mut v = [None, None, None];
+    v[1..].fill(Some(5));
+    assert_eq!(v, [None, Some(5), Some(5)]);
+
+    // filling an empty slice drops the value without touching anything
+    let mut v: [Option<i32>; 0] = [];
+    v.fill(Some(1));
+}
+
+#[test]
+fn test_fill_with() {
+    let mut v = [0; 6];
+    let mut next = 10;
+    v[1..5].fill_with(|| { next += 1; next });
+    assert_eq!(v, [0, 11, 12, 13, 14, 0]);
+
+    let mut calls = 0;
+    let v: &mut [i32] = &mut [];
+    v.fill_with(|| { calls += 1; 0 });
+    assert_eq!(calls, 0);
+}
+
 #[test]
 fn get_range() {
     let v: &[i32] = &[0, 1, 2, 3, 4, 5];
@@ -450,6 +602,28 @@ fn test_iter_folds() {
     assert_eq!(iter.len(), 2);
 }
 
+#[test]
+fn test_split_at_checked() {
+    let v = vec![1, 2, 3, 4, 5];
+
+    assert_eq!(v.split_at_checked(2), Some((&[1, 2][..], &[3, 4, 5][..])));
+
+    let (left, right) = v.split_at_checked(0).unwrap();
+    assert!(left.is_empty());
+    assert_eq!(right, &[1, 2, 3, 4, 5]);
+
+    let (left, right) = v.split_at_checked(5).unwrap();
+    assert_eq!(left, &[1, 2, 3, 4, 5]);
+    assert!(right.is_empty());
+
+    assert_eq!(v.split_at_checked(6), None);
+    assert_eq!(v.split_at_checked(usize::max_value()), None);
+
+    let empty: &[i32] = &[];
+    assert_eq!(empty.split_at_checked(0), Some((empty, empty)));
+    assert_eq!(empty.split_at_checked(1), None);
+}
+
 #[test]
 fn test_rotate_left() {
     const N: usize = 600;
@@ -914,8 +914,8 @@ impl<K, V, S> HashMap<K, V, S>
     /// down no lower than the supplied limit while maintaining the internal rules
     /// and possibly leaving some space in accordance with the resize policy.
     ///
-    /// Panics if the current capacity is smaller than the supplied
-    /// minimum capacity.
+    /// If the current capacity is less than or equal to the lower limit, this
+    /// is a no-op.
     ///
     /// # Examples
     ///
@@ -934,10 +934,8 @@ impl<K, V, S> HashMap<K, V, S>
     /// ```
     #[unstable(feature = "shrink_to", reason = "new API", issue="0")]
     pub fn shrink_to(&mut self, min_capacity: usize) {
-        assert!(self.capacity() >= min_capacity, "Tried to shrink to a larger capacity");
-
         let new_raw_cap = self.resize_policy.raw_capacity(max(self.len(), min_capacity));
-        if self.raw_capacity() != new_raw_cap {
+        if new_raw_cap < self.raw_capacity() {
             let old_table = replace(&mut self.table, RawTable::new(new_raw_cap));
             let old_size = old_table.size();
 
@@ -3333,6 +3331,18 @@ mod test_map {
         assert_eq!(m.remove(&0), Some(0));
     }
 
+    #[test]
+    fn test_shrink_to_larger() {
+        let mut m = HashMap::with_capacity(10);
+        m.insert(1, 2);
+        let cap = m.capacity();
+        m.shrink_to(cap + 10);
+        assert_eq!(m.capacity(), cap);
+        m.shrink_to(cap);
+        assert_eq!(m.capacity(), cap);
+        assert_eq!(m[&1], 2);
+    }
+
     #[test]
     fn test_from_iter() {
         let xs = [(1, 1), (2, 2), (3, 3), (4, 4), (5, 5), (6, 6)];
@@ -300,8 +300,8 @@ impl OsString {
     /// The capacity will remain at least as large as both the length
     /// and the supplied value.
     ///
-    /// Panics if the current capacity is smaller than the supplied
-    /// minimum capacity.
+    /// If the current capacity is less than or equal to the lower limit, this
+    /// is a no-op.
     ///
     /// # Examples
     ///
@@ -880,6 +880,19 @@ mod tests {
         assert!(os_string.inner.into_inner().capacity() >= 3);
     }
 
+    #[test]
+    fn test_os_string_shrink_to() {
+        let mut os_string = OsString::with_capacity(10);
+        os_string.push("abc");
+        os_string.shrink_to(20);
+        assert_eq!(10, os_string.capacity());
+        os_string.shrink_to(5);
+        assert!(os_string.capacity() >= 5);
+        os_string.shrink_to(0);
+        assert!(os_string.capacity() >= 3);
+        assert_eq!(&os_string, "abc");
+    }
+
     #[test]
     fn test_os_string_clear() {
         let mut os_string = OsString::from("abc");